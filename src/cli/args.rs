@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+pub struct Args {
+    positionals: Vec<String>,
+    flags: HashMap<String, Option<String>>
+}
+
+impl Args {
+
+    pub fn parse(raw: Vec<String>, switches: &[&str]) -> Result<Args, String> {
+        let mut positionals = Vec::new();
+        let mut flags = HashMap::new();
+
+        let mut iter = raw.into_iter();
+        while let Some(arg) = iter.next() {
+            let name = match arg.strip_prefix("--") {
+                Some(name) => String::from(name),
+                None => {
+                    positionals.push(arg);
+                    continue;
+                },
+            };
+
+            if let Some((key, value)) = name.split_once('=') {
+                flags.insert(String::from(key), Some(String::from(value)));
+                continue;
+            }
+
+            if switches.contains(&name.as_str()) {
+                flags.insert(name, None);
+                continue;
+            }
+
+            match iter.next() {
+                Some(value) => flags.insert(name, Some(value)),
+                None => return Err(format!("Option '--{}' expects a value.", name)),
+            };
+        }
+
+        Ok(Args { positionals, flags })
+    }
+
+    pub fn positional(&self, index: usize) -> Option<String> {
+        self.positionals.get(index).cloned()
+    }
+
+    pub fn positionals_from(&self, index: usize) -> Vec<String> {
+        self.positionals.iter().skip(index).cloned().collect()
+    }
+
+    pub fn require(&self, index: usize, name: &str) -> Result<String, String> {
+        match self.positional(index) {
+            Some(value) => Ok(value),
+            None => Err(format!("Missing argument <{}>.", name)),
+        }
+    }
+
+    pub fn flag(&self, name: &str) -> Option<String> {
+        self.flags.get(name).cloned().flatten()
+    }
+
+    pub fn require_flag(&self, name: &str) -> Result<String, String> {
+        match self.flag(name) {
+            Some(value) => Ok(value),
+            None => Err(format!("Missing option '--{}'.", name)),
+        }
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.flags.contains_key(name)
+    }
+
+    pub fn number(&self, name: &str) -> Result<Option<usize>, String> {
+        match self.flag(name) {
+            Some(value) => match value.parse::<usize>() {
+                Ok(number) => Ok(Some(number)),
+                Err(_) => Err(format!("Option '--{}' expects a number.", name)),
+            },
+            None => Ok(None),
+        }
+    }
+
+}
\ No newline at end of file
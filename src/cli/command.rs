@@ -0,0 +1,622 @@
+use std::{collections::HashMap, fs::{self, File}, io::{self, Read}, path::Path};
+
+use rust_db_manager_core::{
+    commons::configuration::configuration::Configuration,
+    domain::{
+        action::generate::{action::Action, action_form::ActionForm, form_field::FormField},
+        aggregate::{aggregate_pipeline::AggregatePipeline, aggregate_query::AggregateQuery},
+        change::{e_change_operation::EChangeOperation, watch_query::WatchQuery},
+        collection::{collection_options::CollectionOptions, generate_collection_query::GenerateCollectionQuery},
+        connection_data::ConnectionData,
+        data_base::generate_database_query::GenerateDatabaseQuery,
+        document::document_data::DocumentData,
+        file::file_query::{FileQuery, DEFAULT_BUCKET},
+        operation::operation_query::OperationQuery,
+        filter::{
+            collection_query::CollectionQuery, data_base_query::DataBaseQuery,
+            document_query::DocumentQuery, filter_element::FilterElement,
+            filter_parser::FilterParser, filter_value_attribute::FilterValueAttribute,
+        },
+        permission::{e_service_role::EServiceRole, service_user::ServiceUser},
+        query::saved_query::SavedQuery,
+        transaction::document_transaction::DocumentTransaction,
+    },
+    infrastructure::{
+        db_query_history::DBQueryHistory,
+        db_service::DBService,
+        repository::{e_db_repository::EDBRepository, i_db_repository::IDBRepository, mongo_db::{e_filter_attributes::EFilterAtributtes, mongo_db_actions::{ACTION_COLLECTION_COMPACT, ACTION_COLLECTION_REINDEX}, mongo_db_operation_actions::{ACTION_OPERATIONS_KILL, FIELD_OPERATION, FORM_OPERATIONS}}},
+    },
+    service::service::Service,
+};
+use futures_util::{io::AllowStdIo, stream::StreamExt};
+use serde_json::{json, Value};
+
+#[cfg(feature = "server")]
+use rust_db_manager_core::server::server::Server;
+
+use super::args::Args;
+
+#[cfg(feature = "server")]
+const SERVER_ADDRESS: &str = "127.0.0.1:8080";
+
+const SWITCHES: &[&str] = &["read-only", "protected", "table", "pre-image", "full", "trust-identity-headers", "help"];
+
+const ENV_SERVICE_PASSWORD: &str = "RDM_SERVICE_PASSWORD";
+
+const USAGE: &str = "Usage: rust_db_manager_core <group> <command> [arguments] [options]
+
+Without arguments the interactive terminal UI is started.
+Set KEEP_SERVICES=true so registered services persist between invocations.
+
+Services:
+  service list
+  service add <name> <uri> [--category MongoDB] [--protected] [--read-only]    --protected reads the password from RDM_SERVICE_PASSWORD or stdin
+  service remove <name>
+
+Data bases (require --service <name>):
+  db list
+  db create <data_base>
+  db drop <data_base>
+  db actions [data_base]    user, role and profiler actions; server level (admin) without a data base
+  db execute <action> [data_base]    Action as JSON, as listed by 'db actions'
+  db operations [--namespace <ns>] [--min-seconds <n>] [--table]    running operations, a data base matches all its collections
+  db kill <id> [<id> ...]    kill running operations by op id
+  db profile <data_base>    slow queries from system.profile grouped by query shape
+
+Collections (require --service <name>):
+  collection list <data_base>
+  collection export <data_base> <collection>
+  collection import <data_base> <collection> [--file <path>]    JSON array, stdin by default
+  collection rename <data_base> <collection> <new_name>
+  collection create <data_base> <collection> [--options <json>]    e.g. '{\"category\": \"CAPPED\", \"size\": 1048576}'
+  collection drop <data_base> <collection>
+  collection validate <data_base> <collection> [--full]
+  collection compact <data_base> <collection>
+  collection reindex <data_base> <collection>    standalone servers only
+  collection storage <data_base> <collection>    sizes and fragmentation from collStats
+
+Documents (require --service <name>):
+  doc find <data_base> <collection> [--id <id> | --filter <pipeline>] [--skip <n>] [--limit <n>]
+  doc insert <data_base> <collection> <document>
+  doc update <data_base> <collection> (--id <id> | --filter <pipeline>) <document>
+  doc delete <data_base> <collection> (--id <id> | --filter <pipeline>)
+  doc shell <data_base> <expression>    e.g. 'db.users.find({age: {$gt: 30}}).limit(10)'
+  doc explain <data_base> <collection> [--where <query> | --id <id> | --filter <pipeline>] [--skip <n>] [--limit <n>]
+  doc aggregate <data_base> <collection> <pipeline> [--table]    typed pipeline as JSON
+  doc where <data_base> <collection> <query>    e.g. 'WHERE age > 30 ORDER BY name LIMIT 10'
+  doc watch <data_base> [collection] [--operations insert,update,replace,delete] [--resume <token>] [--pre-image] [--limit <n>]
+
+GridFS files (require --service <name>, --bucket defaults to 'fs'):
+  file buckets <data_base> [--table]
+  file list <data_base> [--bucket <name>] [--name <filename>] [--table]
+  file upload <data_base> <path> [--bucket <name>] [--name <filename>] [--metadata <json>]
+  file download <data_base> <id> --output <path> [--bucket <name>]
+  file delete <data_base> <id> [--bucket <name>]
+  file rename <data_base> <id> <filename> [--bucket <name>]
+
+Transactions (require --service <name>):
+  transaction run [--file <path>]    operations as JSON, stdin by default; all or nothing
+
+Saved queries (require --service <name>):
+  query list
+  query save <name> <data_base> <collection> [--where <query> | --id <id> | --filter <pipeline>] [--skip <n>] [--limit <n>] [--description <text>]
+  query remove <name>
+  query run <name> [<parameter>=<value> ...]    binds {{parameter}} placeholders
+  query history
+
+Server (requires the 'server' feature):
+  serve [--address 127.0.0.1:8080] [--trust-identity-headers]    requests act as the current OS user unless identity headers are trusted
+
+Global options:
+  --service <name>      Registered service to operate on.
+
+Protected services read their password from RDM_SERVICE_PASSWORD.
+The acting user is the current OS user.";
+
+pub async fn run(raw: Vec<String>) -> i32 {
+    let args = match Args::parse(raw, SWITCHES) {
+        Ok(args) => args,
+        Err(error) => return fail(error),
+    };
+
+    let group = args.positional(0).unwrap_or_default();
+    if group.is_empty() || group == "help" || args.has("help") {
+        println!("{}", USAGE);
+        return 0;
+    }
+
+    #[cfg(feature = "server")]
+    if group == "serve" {
+        let address = args.flag("address").unwrap_or(String::from(SERVER_ADDRESS));
+        return match Server::serve(&address, crate::local_user(), args.has("trust-identity-headers")).await {
+            Ok(()) => 0,
+            Err(error) => fail(error.to_string()),
+        };
+    }
+
+    let result = match group.as_str() {
+        "service" => service(&args),
+        "db" | "collection" | "doc" | "transaction" | "file" => with_service(&args).await,
+        "query" => saved_query(&args).await,
+        _ => Err(format!("Unknown command group '{}'. Run 'help' for usage.", group)),
+    };
+
+    match result {
+        Ok(value) => {
+            println!("{}", serde_json::to_string_pretty(&value).unwrap_or_default());
+            0
+        },
+        Err(error) => fail(error),
+    }
+}
+
+fn fail(error: String) -> i32 {
+    eprintln!("{}", json!({ "error": error }));
+    1
+}
+
+fn service_password() -> Option<String> {
+    Configuration::os_env_args().get(ENV_SERVICE_PASSWORD).cloned()
+}
+
+fn read_password() -> Result<String, String> {
+    if let Some(password) = service_password() {
+        return Ok(password);
+    }
+
+    let mut password = String::new();
+    io::stdin().read_line(&mut password).map_err(|e| e.to_string())?;
+    Ok(String::from(password.trim_end_matches(['\r', '\n'])))
+}
+
+fn service(args: &Args) -> Result<Value, String> {
+    let user = crate::local_user();
+    let command = args.require(1, "command")?;
+    match command.as_str() {
+        "list" => {
+            let services = Configuration::find_services_as(&user).map_err(|e| e.message())?;
+            Ok(Value::Array(services.iter()
+                .map(|s| json!({ "name": s.name(), "category": s.category().to_string() }))
+                .collect()))
+        },
+        "add" => {
+            let name = args.require(2, "name")?;
+            let uri = args.require(3, "uri")?;
+
+            let code = args.flag("category").unwrap_or(EDBRepository::MongoDB.to_string());
+            let category = match EDBRepository::from_string(&code) {
+                Some(category) => category,
+                None => return Err(format!("Unknown category '{}'.", code)),
+            };
+
+            let connection_data = ConnectionData::parse(category, uri).map_err(|e| e.message())?;
+
+            let protected = args.has("protected");
+            let password = match protected {
+                true => read_password()?,
+                false => String::new(),
+            };
+            let mut service = DBService::new(name, user.name(), protected, password, connection_data)
+                .map_err(|e| e.message())?;
+            service.set_read_only(args.has("read-only"));
+
+            Configuration::push_service(&service).map_err(|e| e.message())?;
+            Ok(describe(&service))
+        },
+        "remove" => {
+            let name = args.require(2, "name")?;
+            let service = match Configuration::find_service_as(&name, &user, EServiceRole::ADMIN).map_err(|e| e.message())? {
+                Some(service) => service,
+                None => return Err(format!("Service '{}' not found.", name)),
+            };
+            Configuration::remove_service(service.clone()).map_err(|e| e.message())?;
+            Ok(describe(&service))
+        },
+        _ => Err(format!("Unknown service command '{}'.", command)),
+    }
+}
+
+fn describe(service: &DBService) -> Value {
+    json!({
+        "name": service.name(),
+        "owner": service.owner(),
+        "category": service.category().to_string(),
+        "connection": service.connection_data().to_string(),
+        "protected": service.is_protected(),
+        "read_only": service.is_read_only()
+    })
+}
+
+fn find_definition(args: &Args, user: &ServiceUser) -> Result<DBService, String> {
+    let name = args.require_flag("service")?;
+
+    let definition = match Configuration::find_service_as(&name, user, EServiceRole::VIEWER).map_err(|e| e.message())? {
+        Some(service) => service,
+        None => return Err(format!("Service '{}' not found.", name)),
+    };
+
+    if definition.is_protected() {
+        let password = service_password().unwrap_or_default();
+        definition.authorize(user, password).map_err(|e| e.message())?;
+    }
+
+    Ok(definition)
+}
+
+async fn with_service(args: &Args) -> Result<Value, String> {
+    let user = crate::local_user();
+    let definition = find_definition(args, &user)?;
+
+    let service = definition.instance_as(&user).await.map_err(|e| e.message())?;
+
+    match args.positional(0).unwrap_or_default().as_str() {
+        "db" => data_base(args, &service).await,
+        "collection" => collection(args, &service).await,
+        "transaction" => transaction(args, &service).await,
+        "file" => file(args, &service).await,
+        _ => document(args, &service).await,
+    }
+}
+
+async fn saved_query(args: &Args) -> Result<Value, String> {
+    let user = crate::local_user();
+    let mut definition = find_definition(args, &user)?;
+
+    let command = args.require(1, "command")?;
+    match command.as_str() {
+        "list" => Ok(json!(definition.queries())),
+        "history" => Ok(json!(DBQueryHistory::history(&definition.name()))),
+        "save" => {
+            let name = args.require(2, "name")?;
+            let data_base = args.require(3, "data_base")?;
+            let collection = args.require(4, "collection")?;
+
+            let query = match args.flag("where") {
+                Some(expression) => FilterParser::parse(data_base, collection, &expression).map_err(|e| e.message())?,
+                None => DocumentQuery::from(data_base, collection, args.number("skip")?, args.number("limit")?, filter(args)?),
+            };
+
+            let saved = SavedQuery::new(name, user.name(), args.flag("description").unwrap_or_default(), query);
+            definition.save_query(&user, saved.clone()).map_err(|e| e.message())?;
+            Configuration::put_service(definition).map_err(|e| e.message())?;
+            Ok(json!(saved))
+        },
+        "remove" => {
+            let name = args.require(2, "name")?;
+            let removed = definition.remove_query(&user, &name).map_err(|e| e.message())?;
+            if removed.is_none() {
+                return Err(format!("Query '{}' not found.", name));
+            }
+            Configuration::put_service(definition).map_err(|e| e.message())?;
+            Ok(json!(removed))
+        },
+        "run" => {
+            let name = args.require(2, "name")?;
+            let saved = match definition.saved_query(&name) {
+                Some(saved) => saved,
+                None => return Err(format!("Query '{}' not found.", name)),
+            };
+
+            let mut values = HashMap::new();
+            for parameter in args.positionals_from(3) {
+                match parameter.split_once('=') {
+                    Some((key, value)) => values.insert(String::from(key), String::from(value)),
+                    None => return Err(format!("Parameter '{}' must be written as <name>=<value>.", parameter)),
+                };
+            }
+
+            let query = saved.bind(&values).map_err(|e| e.message())?;
+            let service = definition.instance_as(&user).await.map_err(|e| e.message())?;
+            let data = service.find_query(&query).await.map_err(|e| e.message())?;
+            Ok(documents_as_json(&data.documents()))
+        },
+        _ => Err(format!("Unknown query command '{}'.", command)),
+    }
+}
+
+async fn data_base<T: IDBRepository>(args: &Args, service: &Service<T>) -> Result<Value, String> {
+    let command = args.require(1, "command")?;
+    match command.as_str() {
+        "list" => Ok(json!(service.data_base_find_all().await.map_err(|e| e.message())?)),
+        "create" => {
+            let query = GenerateDatabaseQuery::new(args.require(2, "data_base")?);
+            Ok(json!(service.data_base_create(&query).await.map_err(|e| e.message())?))
+        },
+        "drop" => {
+            let query = GenerateDatabaseQuery::new(args.require(2, "data_base")?);
+            Ok(json!(service.data_base_drop(&query).await.map_err(|e| e.message())?))
+        },
+        "actions" => match args.positional(2) {
+            Some(data_base) => Ok(json!(service.data_base_actions(&DataBaseQuery::from(data_base)).await.map_err(|e| e.message())?)),
+            None => Ok(json!(service.actions().await.map_err(|e| e.message())?)),
+        },
+        "execute" => {
+            let action: Action = serde_json::from_str(&args.require(2, "action")?)
+                .map_err(|e| format!("Invalid action: {}", e))?;
+            match args.positional(3) {
+                Some(data_base) => Ok(json!(service.data_base_execute_action(&DataBaseQuery::from(data_base), &action).await.map_err(|e| e.message())?)),
+                None => Ok(json!(service.execute_action(&action).await.map_err(|e| e.message())?)),
+            }
+        },
+        "operations" => {
+            let min_seconds = args.number("min-seconds")?.map(|n| n as u64);
+            let query = OperationQuery::from(args.flag("namespace"), min_seconds);
+            if args.has("table") {
+                return Ok(json!(service.operations_table(&query).await.map_err(|e| e.message())?));
+            }
+            Ok(json!(service.operations(&query).await.map_err(|e| e.message())?))
+        },
+        "kill" => {
+            let ids = args.positionals_from(2);
+            if ids.is_empty() {
+                return Err(String::from("Missing argument <id>."));
+            }
+            let fields = ids.into_iter()
+                .map(|id| vec![FormField::new(String::from(FIELD_OPERATION), id)])
+                .collect();
+            let action = Action::new(String::from(ACTION_OPERATIONS_KILL), vec![ActionForm::new(String::from(FORM_OPERATIONS), fields)]);
+            Ok(json!(service.execute_action(&action).await.map_err(|e| e.message())?))
+        },
+        "profile" => {
+            let query = DataBaseQuery::from(args.require(2, "data_base")?);
+            Ok(json!(service.data_base_profile(&query).await.map_err(|e| e.message())?))
+        },
+        _ => Err(format!("Unknown db command '{}'.", command)),
+    }
+}
+
+async fn collection<T: IDBRepository>(args: &Args, service: &Service<T>) -> Result<Value, String> {
+    let command = args.require(1, "command")?;
+    let data_base = args.require(2, "data_base")?;
+
+    if command == "list" {
+        let query = DataBaseQuery::from(data_base);
+        return Ok(json!(service.collection_find_all(&query).await.map_err(|e| e.message())?));
+    }
+
+    let query = CollectionQuery::from(data_base.clone(), args.require(3, "collection")?);
+    match command.as_str() {
+        "export" => {
+            let documents = service.collection_export(&query).await.map_err(|e| e.message())?;
+            Ok(documents_as_json(&documents))
+        },
+        "import" => {
+            let json = input(args)?;
+
+            let values: Vec<Value> = serde_json::from_str(&json).map_err(|e| format!("Invalid JSON array: {}", e))?;
+            let documents = values.iter().map(|v| v.to_string()).collect();
+
+            service.collection_import(&query, documents).await.map_err(|e| e.message())?;
+            Ok(json!({ "imported": values.len() }))
+        },
+        "rename" => {
+            let name = args.require(4, "new_name")?;
+            Ok(json!(service.collection_rename(&query, &name).await.map_err(|e| e.message())?))
+        },
+        "create" => {
+            let options: CollectionOptions = match args.flag("options") {
+                Some(options) => serde_json::from_str(&options).map_err(|e| format!("Invalid collection options: {}", e))?,
+                None => CollectionOptions::new(),
+            };
+            let generate = GenerateCollectionQuery::from_options(data_base, query.collection(), Vec::new(), options);
+            Ok(json!(service.collection_create(&generate).await.map_err(|e| e.message())?))
+        },
+        "drop" => {
+            let generate = GenerateCollectionQuery::from_collection(data_base, query.collection());
+            Ok(json!(service.collection_drop(&generate).await.map_err(|e| e.message())?))
+        },
+        "validate" => Ok(json!(service.collection_validate(&query, args.has("full")).await.map_err(|e| e.message())?)),
+        "compact" | "reindex" => {
+            let code = match command.as_str() {
+                "compact" => ACTION_COLLECTION_COMPACT,
+                _ => ACTION_COLLECTION_REINDEX,
+            };
+            let action = Action::new(String::from(code), Vec::new());
+            Ok(json!(service.collection_execute_action(&query, &action).await.map_err(|e| e.message())?))
+        },
+        "storage" => Ok(json!(service.collection_storage(&query).await.map_err(|e| e.message())?)),
+        _ => Err(format!("Unknown collection command '{}'.", command)),
+    }
+}
+
+async fn document<T: IDBRepository>(args: &Args, service: &Service<T>) -> Result<Value, String> {
+    let command = args.require(1, "command")?;
+    let data_base = args.require(2, "data_base")?;
+
+    if command == "shell" {
+        let query = service.parse_query(&data_base, &args.require(3, "expression")?).await.map_err(|e| e.message())?;
+        let data = service.find_query(&query).await.map_err(|e| e.message())?;
+        return Ok(documents_as_json(&data.documents()));
+    }
+
+    if command == "watch" {
+        return watch(args, service, data_base).await;
+    }
+
+    let collection = args.require(3, "collection")?;
+
+    match command.as_str() {
+        "find" => {
+            let query = DocumentQuery::from(data_base, collection, args.number("skip")?, args.number("limit")?, filter(args)?);
+            let data = service.find_query(&query).await.map_err(|e| e.message())?;
+            Ok(documents_as_json(&data.documents()))
+        },
+        "explain" => {
+            let query = match args.flag("where") {
+                Some(expression) => FilterParser::parse(data_base, collection, &expression).map_err(|e| e.message())?,
+                None => DocumentQuery::from(data_base, collection, args.number("skip")?, args.number("limit")?, filter(args)?),
+            };
+            Ok(json!(service.explain(&query).await.map_err(|e| e.message())?))
+        },
+        "aggregate" => {
+            let pipeline: AggregatePipeline = serde_json::from_str(&args.require(4, "pipeline")?)
+                .map_err(|e| format!("Invalid aggregation pipeline: {}", e))?;
+            let query = AggregateQuery::from(data_base, collection, pipeline);
+            if args.has("table") {
+                return Ok(json!(service.aggregate_table(&query).await.map_err(|e| e.message())?));
+            }
+            let documents = service.aggregate(&query).await.map_err(|e| e.message())?;
+            Ok(documents_as_json(&documents))
+        },
+        "where" => {
+            let query = FilterParser::parse(data_base, collection, &args.require(4, "query")?).map_err(|e| e.message())?;
+            let data = service.find_query(&query).await.map_err(|e| e.message())?;
+            Ok(documents_as_json(&data.documents()))
+        },
+        "insert" => {
+            let query = CollectionQuery::from(data_base, collection);
+            let document = service.insert(&query, &args.require(4, "document")?).await.map_err(|e| e.message())?;
+            Ok(documents_as_json(&[document]))
+        },
+        "update" => {
+            let query = DocumentQuery::from(data_base, collection, None, None, Some(require_filter(args)?));
+            let documents = service.update(&query, &args.require(4, "document")?).await.map_err(|e| e.message())?;
+            Ok(documents_as_json(&documents))
+        },
+        "delete" => {
+            let query = DocumentQuery::from(data_base, collection, None, None, Some(require_filter(args)?));
+            let documents = service.delete(&query).await.map_err(|e| e.message())?;
+            Ok(documents_as_json(&documents))
+        },
+        _ => Err(format!("Unknown doc command '{}'.", command)),
+    }
+}
+
+async fn watch<T: IDBRepository>(args: &Args, service: &Service<T>, data_base: String) -> Result<Value, String> {
+    let mut operations = Vec::new();
+    if let Some(codes) = args.flag("operations") {
+        for code in codes.split(',').map(|c| c.trim()).filter(|c| !c.is_empty()) {
+            match EChangeOperation::from_string(&code.to_uppercase()) {
+                Some(operation) => operations.push(operation),
+                None => return Err(format!("Unknown change operation '{}'.", code)),
+            }
+        }
+    }
+
+    let query = WatchQuery::from(data_base, args.positional(3), operations, args.flag("resume"), args.has("pre-image"));
+    let limit = args.number("limit")?;
+
+    let mut stream = service.watch(&query).await.map_err(|e| e.message())?;
+
+    let mut count = 0;
+    let mut resume_token = query.resume_token();
+    while limit.is_none_or(|l| count < l) {
+        let event = match stream.next().await {
+            Some(event) => event.map_err(|e| e.message())?,
+            None => break,
+        };
+
+        println!("{}", serde_json::to_string(&event).unwrap_or_default());
+        resume_token = Some(event.resume_token());
+        count += 1;
+    }
+
+    Ok(json!({ "events": count, "resume_token": resume_token }))
+}
+
+async fn file<T: IDBRepository>(args: &Args, service: &Service<T>) -> Result<Value, String> {
+    let command = args.require(1, "command")?;
+    let data_base = args.require(2, "data_base")?;
+
+    if command == "buckets" {
+        let query = DataBaseQuery::from(data_base);
+        if args.has("table") {
+            return Ok(json!(service.file_buckets_table(&query).await.map_err(|e| e.message())?));
+        }
+        return Ok(json!(service.file_buckets(&query).await.map_err(|e| e.message())?));
+    }
+
+    let bucket = args.flag("bucket").unwrap_or(String::from(DEFAULT_BUCKET));
+    let query = FileQuery::from_filename(data_base, bucket, args.flag("name"));
+
+    match command.as_str() {
+        "list" => {
+            if args.has("table") {
+                return Ok(json!(service.file_table(&query).await.map_err(|e| e.message())?));
+            }
+            Ok(json!(service.file_find_all(&query).await.map_err(|e| e.message())?))
+        },
+        "upload" => {
+            let path = args.require(3, "path")?;
+            let filename = match query.filename() {
+                Some(filename) => filename,
+                None => Path::new(&path).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or(path.clone()),
+            };
+            let source = File::open(&path).map_err(|e| e.to_string())?;
+            let file = service.file_upload(&query, &filename, args.flag("metadata").as_deref(), &mut AllowStdIo::new(source)).await.map_err(|e| e.message())?;
+            Ok(json!(file))
+        },
+        "download" => {
+            let id = args.require(3, "id")?;
+            let output = args.require_flag("output")?;
+            let destination = File::create(&output).map_err(|e| e.to_string())?;
+            service.file_download(&query, &id, &mut AllowStdIo::new(destination)).await.map_err(|e| e.message())?;
+            Ok(json!({ "id": id, "output": output }))
+        },
+        "delete" => {
+            let id = args.require(3, "id")?;
+            Ok(json!(service.file_delete(&query, &id).await.map_err(|e| e.message())?))
+        },
+        "rename" => {
+            let id = args.require(3, "id")?;
+            let filename = args.require(4, "filename")?;
+            Ok(json!(service.file_rename(&query, &id, &filename).await.map_err(|e| e.message())?))
+        },
+        _ => Err(format!("Unknown file command '{}'.", command)),
+    }
+}
+
+async fn transaction<T: IDBRepository>(args: &Args, service: &Service<T>) -> Result<Value, String> {
+    let command = args.require(1, "command")?;
+    match command.as_str() {
+        "run" => {
+            let transaction: DocumentTransaction = serde_json::from_str(&input(args)?)
+                .map_err(|e| format!("Invalid transaction: {}", e))?;
+            let results = service.transaction(&transaction).await.map_err(|e| e.message())?;
+            Ok(Value::Array(results.iter().map(|r| documents_as_json(r)).collect()))
+        },
+        _ => Err(format!("Unknown transaction command '{}'.", command)),
+    }
+}
+
+fn input(args: &Args) -> Result<String, String> {
+    match args.flag("file") {
+        Some(path) => fs::read_to_string(path).map_err(|e| e.to_string()),
+        None => {
+            let mut buffer = String::new();
+            io::stdin().read_to_string(&mut buffer).map_err(|e| e.to_string())?;
+            Ok(buffer)
+        },
+    }
+}
+
+fn filter(args: &Args) -> Result<Option<FilterElement>, String> {
+    if let Some(id) = args.flag("id") {
+        let attributes = vec![FilterValueAttribute::new(EFilterAtributtes::OID.to_string(), String::from("true"))];
+        let mut filter = FilterElement::new();
+        filter.push(FilterElement::id_string(String::from("_id"), id, attributes));
+        return Ok(Some(filter));
+    }
+
+    if let Some(pipeline) = args.flag("filter") {
+        if serde_json::from_str::<Vec<Value>>(&pipeline).is_err() {
+            return Err(String::from("Option '--filter' expects a JSON aggregation pipeline array."));
+        }
+        return Ok(Some(FilterElement::query(pipeline, Vec::new())));
+    }
+
+    Ok(None)
+}
+
+fn require_filter(args: &Args) -> Result<FilterElement, String> {
+    match filter(args)? {
+        Some(filter) => Ok(filter),
+        None => Err(String::from("Option '--id' or '--filter' is required.")),
+    }
+}
+
+fn documents_as_json(documents: &[DocumentData]) -> Value {
+    Value::Array(documents.iter()
+        .map(|d| serde_json::from_str(&d.document()).unwrap_or(Value::String(d.document())))
+        .collect())
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap, env, fs::{self, File}, io::{Read, Write}, process::Command, sync::Mutex, time::{SystemTime, UNIX_EPOCH}
+    collections::HashMap, env, fs::{self, File, OpenOptions}, io::{Read, Write}, process::Command, sync::Mutex, time::{SystemTime, UNIX_EPOCH}
 };
 
 use cargo_metadata::{CargoOpt, MetadataCommand};
@@ -7,8 +7,9 @@ use lazy_static::lazy_static;
 use uuid::Uuid;
 
 use crate::{
-    commons::exception::configuration_exception::ConfigurationException,
-    infrastructure::{db_service::DBService, db_service_lite::DBServiceLite},
+    commons::exception::{configuration_exception::ConfigurationException, connect_exception::ConnectException},
+    domain::permission::{e_service_role::EServiceRole, service_user::ServiceUser},
+    infrastructure::{db_query_history::DBQueryHistory, db_service::DBService, db_service_lite::DBServiceLite, db_session_manager::DBSessionManager},
 };
 
 const ENV_KEEP_SERVICES: &str = "KEEP_SERVICES";
@@ -152,7 +153,12 @@ impl Configuration {
         Ok(config.services.iter().map(|s| DBServiceLite::new(s.1.name(), s.1.category())).collect())
     }
 
+    #[deprecated(note = "does not check permissions, use `find_service_as`")]
     pub fn find_service(key: &str) -> Result<Option<DBService>, ConfigurationException> {
+        Self::find_service_unchecked(key)
+    }
+
+    pub(crate) fn find_service_unchecked(key: &str) -> Result<Option<DBService>, ConfigurationException> {
         let mut instance = INSTANCE.lock().expect("Could not lock mutex");
         
         let config = match instance.as_mut() {
@@ -163,6 +169,31 @@ impl Configuration {
         Ok(config.services.get(key).cloned())
     }
 
+    pub fn find_services_as(user: &ServiceUser) -> Result<Vec<DBServiceLite>, ConfigurationException> {
+        let mut instance = INSTANCE.lock().expect("Could not lock mutex");
+        
+        let config = match instance.as_mut() {
+            Some(config) => config,
+            None => return Err(ConfigurationException::new("Configuration is not initialized.")),
+        };
+        
+        Ok(config.services.values()
+            .filter(|s| s.role(user).is_some())
+            .map(|s| DBServiceLite::new(s.name(), s.category()))
+            .collect())
+    }
+
+    pub fn find_service_as(key: &str, user: &ServiceUser, required: EServiceRole) -> Result<Option<DBService>, ConnectException> {
+        let service = match Self::find_service_unchecked(key)? {
+            Some(service) => service,
+            None => return Ok(None),
+        };
+
+        service.check_role(user, required)?;
+
+        Ok(Some(service))
+    }
+
     pub fn push_service(service: &DBService) -> Result<&DBService, ConfigurationException> {
         let mut instance = INSTANCE.lock().expect("Could not lock mutex");
         
@@ -209,6 +240,9 @@ impl Configuration {
         let result = config.services.remove(&service.name());
         Self::write_cached(config)?;
 
+        DBSessionManager::revoke_service(&service.name());
+        DBQueryHistory::clear(&service.name());
+
         Ok(result)
     }
 
@@ -264,6 +298,46 @@ impl Configuration {
         return Ok(());
     }
 
+    pub fn read_cache_file(file: &str) -> Option<String> {
+        if !Self::instance().is_ok_and(|c| c.keep_services) {
+            return None;
+        }
+
+        fs::read_to_string(format!("{}/{}", CACHE_DIRECTORY, file)).ok()
+    }
+
+    pub fn write_cache_file(file: &str, content: &str) -> Result<(), ConfigurationException> {
+        if !Self::instance()?.keep_services {
+            return Ok(());
+        }
+
+        Self::check_cache_directory()?;
+
+        match fs::write(format!("{}/{}", CACHE_DIRECTORY, file), content) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(ConfigurationException::new(&err.to_string())),
+        }
+    }
+
+    pub fn append_cache_file(file: &str, content: &str) -> Result<(), ConfigurationException> {
+        if !Self::instance()?.keep_services {
+            return Ok(());
+        }
+
+        Self::check_cache_directory()?;
+
+        let result = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(format!("{}/{}", CACHE_DIRECTORY, file))
+            .and_then(|mut f| f.write_all(content.as_bytes()));
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(ConfigurationException::new(&err.to_string())),
+        }
+    }
+
     fn check_cache_directory() -> Result<(), ConfigurationException> {
         match fs::create_dir_all(CACHE_DIRECTORY) {
             Ok(_) => Ok(()),
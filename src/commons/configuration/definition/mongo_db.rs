@@ -64,6 +64,13 @@ pub fn mongo_db_collection() -> String {
                     ],
                     "reference": []
                 }
+            ],
+            "categories": [
+                "STANDARD",
+                "CAPPED",
+                "TIME_SERIES",
+                "CLUSTERED",
+                "VIEW"
             ]
         }
     ).to_string()
@@ -116,6 +123,55 @@ pub fn mongo_db_filter() -> String {
                         "STRING"
                     ]
                 },
+                {
+                    "code": "OPERATOR",
+                    "name": "Operator",
+                    "description": "Comparison operator",
+                    "values": [
+                        {
+                            "key": "EQ",
+                            "value": "EQ",
+                            "default": true
+                        },
+                        {
+                            "key": "NE",
+                            "value": "NE",
+                            "default": false
+                        },
+                        {
+                            "key": "GT",
+                            "value": "GT",
+                            "default": false
+                        },
+                        {
+                            "key": "GTE",
+                            "value": "GTE",
+                            "default": false
+                        },
+                        {
+                            "key": "LT",
+                            "value": "LT",
+                            "default": false
+                        },
+                        {
+                            "key": "LTE",
+                            "value": "LTE",
+                            "default": false
+                        },
+                        {
+                            "key": "LIKE",
+                            "value": "LIKE",
+                            "default": false
+                        }
+                    ],
+                    "applies": [
+                        "ID_STRING",
+                        "ID_NUMERIC",
+                        "STRING",
+                        "NUMERIC",
+                        "BOOLEAN"
+                    ]
+                },
             ]
         }
     ).to_string()
@@ -154,6 +210,22 @@ pub fn mongo_db_collection_actions() -> String {
                                         {
                                             "key": "DESC",
                                             "value": "-1"
+                                        },
+                                        {
+                                            "key": "TEXT",
+                                            "value": "text"
+                                        },
+                                        {
+                                            "key": "HASHED",
+                                            "value": "hashed"
+                                        },
+                                        {
+                                            "key": "2DSPHERE",
+                                            "value": "2dsphere"
+                                        },
+                                        {
+                                            "key": "WILDCARD",
+                                            "value": "wildcard"
                                         }
                                     ]
                                 }
@@ -185,11 +257,103 @@ pub fn mongo_db_collection_actions() -> String {
                                             "value": "false"
                                         }
                                     ]
+                                },
+                                {
+                                    "order": 3,
+                                    "code": "SPARSE",
+                                    "name": "Sparse",
+                                    "sw_key": false,
+                                    "values": [
+                                        {
+                                            "key": "True",
+                                            "value": "true"
+                                        },
+                                        {
+                                            "key": "False",
+                                            "value": "false"
+                                        }
+                                    ]
+                                },
+                                {
+                                    "order": 4,
+                                    "code": "HIDDEN",
+                                    "name": "Hidden",
+                                    "sw_key": false,
+                                    "values": [
+                                        {
+                                            "key": "True",
+                                            "value": "true"
+                                        },
+                                        {
+                                            "key": "False",
+                                            "value": "false"
+                                        }
+                                    ]
+                                },
+                                {
+                                    "order": 5,
+                                    "code": "EXPIRE_AFTER_SECONDS",
+                                    "name": "Expire after (seconds)",
+                                    "sw_key": false,
+                                    "values": []
+                                },
+                                {
+                                    "order": 6,
+                                    "code": "PARTIAL_FILTER",
+                                    "name": "Partial filter",
+                                    "sw_key": false,
+                                    "values": []
+                                },
+                                {
+                                    "order": 7,
+                                    "code": "COLLATION",
+                                    "name": "Collation",
+                                    "sw_key": false,
+                                    "values": []
+                                }
+                            ]
+                        }
+                    ]
+                }
+            },
+            {
+                "action": "COLLECTION_VALIDATE",
+                "title": "Validate",
+                "form": {
+                    "sw_query": false,
+                    "forms":  [
+                        {
+                            "code": "VALIDATE",
+                            "sw_vector": false,
+                            "fields": [
+                                {
+                                    "order": 1,
+                                    "code": "FULL",
+                                    "name": "Full",
+                                    "sw_key": false,
+                                    "values": [
+                                        {
+                                            "key": "False",
+                                            "value": "false"
+                                        },
+                                        {
+                                            "key": "True",
+                                            "value": "true"
+                                        }
+                                    ]
                                 }
                             ]
                         }
                     ]
                 }
+            },
+            {
+                "action": "COLLECTION_COMPACT",
+                "title": "Compact"
+            },
+            {
+                "action": "COLLECTION_REINDEX",
+                "title": "Rebuild indexes"
             }
         ]
     ).to_string()
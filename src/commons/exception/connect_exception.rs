@@ -1,8 +1,11 @@
 use std::fmt;
 use std::error::Error;
 
+use super::configuration_exception::ConfigurationException;
+
 #[derive(Debug, Clone)]
 pub struct ConnectException {
+    status: u16,
     message: String,
 }
 
@@ -17,15 +20,41 @@ impl fmt::Display for ConnectException {
 impl Error for ConnectException {}
 
 impl ConnectException {
+
+    pub const BAD_REQUEST: u16 = 400;
+    pub const UNAUTHORIZED: u16 = 401;
+    pub const FORBIDDEN: u16 = 403;
+    pub const NOT_FOUND: u16 = 404;
+    pub const CONFLICT: u16 = 409;
+    pub const TOO_MANY_REQUESTS: u16 = 429;
+    pub const INTERNAL_ERROR: u16 = 500;
+    pub const NOT_IMPLEMENTED: u16 = 501;
+    pub const TIMEOUT: u16 = 504;
     
     pub fn new(message: String) -> ConnectException {
-        return ConnectException {
-            message
-        };
+        return ConnectException::from_status(ConnectException::INTERNAL_ERROR, message);
+    }
+
+    pub fn from_status(status: u16, message: String) -> ConnectException {
+        ConnectException {
+            status, message
+        }
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
     }
     
     pub fn message(&self) -> String {
         return self.message.clone();
     }
 
+}
+
+impl From<ConfigurationException> for ConnectException {
+
+    fn from(exception: ConfigurationException) -> Self {
+        ConnectException::new(exception.message())
+    }
+
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
-use crate::domain::{document::document_key::DocumentKey, e_json_type::EJSONType, filter::{filter_element::FilterElement, filter_value_attribute::FilterValueAttribute}};
+
+use serde_json::Value;
+
+use crate::domain::{document::{document_data::DocumentData, document_key::DocumentKey}, file::{file_bucket::FileBucket, file_data::FileData}, operation::operation_data::OperationData, table::definition::{table_definition::TableDefinition, table_row_definition::TableRowDefinition}, e_json_type::EJSONType, filter::{filter_element::FilterElement, filter_value_attribute::FilterValueAttribute}};
 
 pub fn document_keys_to_filter_element(documents: Vec<DocumentKey>) -> FilterElement {
     let mut filter = FilterElement::new();
@@ -26,4 +29,122 @@ pub fn document_keys_to_filter_element(documents: Vec<DocumentKey>) -> FilterEle
     }
 
     filter
+}
+
+pub fn documents_as_table(title: String, documents: &[DocumentData]) -> TableDefinition {
+    let values: Vec<Value> = documents.iter()
+        .map(|d| serde_json::from_str(&d.document()).unwrap_or(Value::Null))
+        .collect();
+
+    let mut columns: Vec<String> = Vec::new();
+    for value in &values {
+        if let Value::Object(map) = value {
+            for key in map.keys() {
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+    }
+
+    let mut table = TableDefinition::new(title);
+
+    let mut header = TableRowDefinition::new();
+    for column in &columns {
+        header.push_title(column.clone());
+    }
+    table.push(header);
+
+    for value in &values {
+        let mut row = TableRowDefinition::new();
+        for column in &columns {
+            let cell = match value.get(column) {
+                Some(Value::String(text)) => text.clone(),
+                Some(Value::Null) | None => String::new(),
+                Some(other) => other.to_string(),
+            };
+            row.push(cell);
+        }
+        table.push(row);
+    }
+
+    table
+}
+
+pub fn buckets_as_table(title: String, buckets: &[FileBucket]) -> TableDefinition {
+    let mut table = TableDefinition::new(title);
+
+    let mut header = TableRowDefinition::new();
+    header.push_title(String::from("Bucket"));
+    header.push_title(String::from("Files"));
+    header.push_title(String::from("Size (bytes)"));
+    table.push(header);
+
+    for bucket in buckets {
+        let mut row = TableRowDefinition::new();
+        row.push(bucket.name());
+        row.push(bucket.files().to_string());
+        row.push(bucket.size().to_string());
+        table.push(row);
+    }
+
+    table
+}
+
+pub fn files_as_table(title: String, files: &[FileData]) -> TableDefinition {
+    let mut table = TableDefinition::new(title);
+
+    let mut header = TableRowDefinition::new();
+    header.push_title(String::from("Id"));
+    header.push_title(String::from("Filename"));
+    header.push_title(String::from("Size (bytes)"));
+    header.push_title(String::from("Chunk size"));
+    header.push_title(String::from("Uploaded"));
+    header.push_title(String::from("Metadata"));
+    table.push(header);
+
+    for file in files {
+        let mut row = TableRowDefinition::new();
+        row.push(file.id());
+        row.push(file.filename());
+        row.push(file.length().to_string());
+        row.push(file.chunk_size().to_string());
+        row.push(file.upload_date());
+        row.push(file.metadata().unwrap_or_default());
+        table.push(row);
+    }
+
+    table
+}
+
+pub fn operations_as_table(title: String, operations: &[OperationData]) -> TableDefinition {
+    let mut table = TableDefinition::new(title);
+
+    let mut header = TableRowDefinition::new();
+    header.push_title(String::from("Id"));
+    header.push_title(String::from("Operation"));
+    header.push_title(String::from("Namespace"));
+    header.push_title(String::from("Duration (ms)"));
+    header.push_title(String::from("Client"));
+    header.push_title(String::from("Plan summary"));
+    header.push_title(String::from("Description"));
+    table.push(header);
+
+    for operation in operations {
+        let mut row = TableRowDefinition::new();
+        row.push(operation.id());
+        row.push(operation.operation());
+        row.push(operation.namespace());
+        row.push(operation.duration().to_string());
+        row.push(operation.client());
+        row.push(operation.plan_summary());
+        row.push(operation.description());
+        table.push(row);
+    }
+
+    table
+}
+
+pub fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
 }
\ No newline at end of file
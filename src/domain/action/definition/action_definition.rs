@@ -1,10 +1,10 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::domain::table::definition::table_definition::TableDefinition;
 
 use super::action_form_collection::ActionFormCollection;
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct ActionDefinition {
     action: String,
     title: String,
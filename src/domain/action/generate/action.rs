@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use super::action_form::ActionForm;
 
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct Action {
     action: String,
     form: Vec<ActionForm>
@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use super::form_field::FormField;
 
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct ActionForm {
     code: String,
     fields: Vec<Vec<FormField>>
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use super::e_aggregate_accumulator::EAggregateAccumulator;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AggregateAccumulator {
+    alias: String,
+    operator: EAggregateAccumulator,
+    #[serde(default)]
+    field: Option<String>
+}
+
+impl AggregateAccumulator {
+
+    pub fn new(alias: String, operator: EAggregateAccumulator, field: String) -> Self {
+        Self {
+            alias, operator, field: Some(field)
+        }
+    }
+
+    pub fn count(alias: String) -> Self {
+        Self {
+            alias, operator: EAggregateAccumulator::COUNT, field: None
+        }
+    }
+
+    pub fn alias(&self) -> String {
+        self.alias.clone()
+    }
+
+    pub fn operator(&self) -> EAggregateAccumulator {
+        self.operator
+    }
+
+    pub fn field(&self) -> Option<String> {
+        self.field.clone()
+    }
+
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use super::aggregate_pipeline::AggregatePipeline;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AggregateFacet {
+    name: String,
+    pipeline: AggregatePipeline
+}
+
+impl AggregateFacet {
+
+    pub fn new(name: String, pipeline: AggregatePipeline) -> Self {
+        Self {
+            name, pipeline
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn pipeline(&self) -> AggregatePipeline {
+        self.pipeline.clone()
+    }
+
+}
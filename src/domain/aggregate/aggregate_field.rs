@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AggregateField {
+    alias: String,
+    expression: String
+}
+
+impl AggregateField {
+
+    pub fn new(alias: String, expression: String) -> Self {
+        Self {
+            alias, expression
+        }
+    }
+
+    pub fn field(field: String) -> Self {
+        Self {
+            expression: format!("${}", field), alias: field
+        }
+    }
+
+    pub fn include(field: String) -> Self {
+        Self {
+            alias: field, expression: String::from("1")
+        }
+    }
+
+    pub fn exclude(field: String) -> Self {
+        Self {
+            alias: field, expression: String::from("0")
+        }
+    }
+
+    pub fn alias(&self) -> String {
+        self.alias.clone()
+    }
+
+    pub fn expression(&self) -> String {
+        self.expression.clone()
+    }
+
+}
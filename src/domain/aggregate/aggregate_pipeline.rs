@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::filter::{document_sort::DocumentSort, filter_element::FilterElement};
+
+use super::{aggregate_accumulator::AggregateAccumulator, aggregate_facet::AggregateFacet, aggregate_field::AggregateField, aggregate_stage::AggregateStage};
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AggregatePipeline {
+    stages: Vec<AggregateStage>
+}
+
+impl AggregatePipeline {
+
+    pub fn new() -> Self {
+        Self {
+            stages: Vec::new()
+        }
+    }
+
+    pub fn from(stages: Vec<AggregateStage>) -> Self {
+        Self {
+            stages
+        }
+    }
+
+    pub fn stages(&self) -> Vec<AggregateStage> {
+        self.stages.clone()
+    }
+
+    pub fn push(mut self, stage: AggregateStage) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
+    pub fn filter(self, filter: FilterElement) -> Self {
+        self.push(AggregateStage::MATCH(filter))
+    }
+
+    pub fn group(self, keys: Vec<AggregateField>, accumulators: Vec<AggregateAccumulator>) -> Self {
+        self.push(AggregateStage::GROUP { keys, accumulators })
+    }
+
+    pub fn project(self, fields: Vec<AggregateField>) -> Self {
+        self.push(AggregateStage::PROJECT(fields))
+    }
+
+    pub fn unwind(self, field: String, sw_preserve_empty: bool) -> Self {
+        self.push(AggregateStage::UNWIND { field, sw_preserve_empty })
+    }
+
+    pub fn lookup(self, from: String, local_field: String, foreign_field: String, alias: String) -> Self {
+        self.push(AggregateStage::LOOKUP { from, local_field, foreign_field, alias })
+    }
+
+    pub fn sort(self, sort: Vec<DocumentSort>) -> Self {
+        self.push(AggregateStage::SORT(sort))
+    }
+
+    pub fn skip(self, skip: usize) -> Self {
+        self.push(AggregateStage::SKIP(skip))
+    }
+
+    pub fn limit(self, limit: usize) -> Self {
+        self.push(AggregateStage::LIMIT(limit))
+    }
+
+    pub fn facet(self, facets: Vec<AggregateFacet>) -> Self {
+        self.push(AggregateStage::FACET(facets))
+    }
+
+    pub fn add_fields(self, fields: Vec<AggregateField>) -> Self {
+        self.push(AggregateStage::ADD_FIELDS(fields))
+    }
+
+}
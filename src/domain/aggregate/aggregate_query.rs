@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use super::aggregate_pipeline::AggregatePipeline;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AggregateQuery {
+    data_base: String,
+    collection: String,
+    pipeline: AggregatePipeline
+}
+
+impl AggregateQuery {
+
+    pub fn from(data_base: String, collection: String, pipeline: AggregatePipeline) -> Self {
+        Self {
+            data_base, collection, pipeline
+        }
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn collection(&self) -> String {
+        self.collection.clone()
+    }
+
+    pub fn pipeline(&self) -> AggregatePipeline {
+        self.pipeline.clone()
+    }
+
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::filter::{document_sort::DocumentSort, filter_element::FilterElement};
+
+use super::{aggregate_accumulator::AggregateAccumulator, aggregate_facet::AggregateFacet, aggregate_field::AggregateField};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum AggregateStage {
+    MATCH(FilterElement),
+    GROUP {
+        keys: Vec<AggregateField>,
+        accumulators: Vec<AggregateAccumulator>
+    },
+    PROJECT(Vec<AggregateField>),
+    UNWIND {
+        field: String,
+        #[serde(default)]
+        sw_preserve_empty: bool
+    },
+    LOOKUP {
+        from: String,
+        local_field: String,
+        foreign_field: String,
+        alias: String
+    },
+    SORT(Vec<DocumentSort>),
+    SKIP(usize),
+    LIMIT(usize),
+    FACET(Vec<AggregateFacet>),
+    ADD_FIELDS(Vec<AggregateField>)
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, Deserialize, Serialize)]
+pub enum EAggregateAccumulator {
+    SUM,
+    AVG,
+    MIN,
+    MAX,
+    COUNT,
+    FIRST,
+    LAST,
+    PUSH,
+    ADD_TO_SET
+}
+
+impl EAggregateAccumulator {
+
+    pub fn items() -> Vec<EAggregateAccumulator> {
+        EAggregateAccumulator::iter().collect()
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EAggregateAccumulator::SUM => String::from("SUM"),
+            EAggregateAccumulator::AVG => String::from("AVG"),
+            EAggregateAccumulator::MIN => String::from("MIN"),
+            EAggregateAccumulator::MAX => String::from("MAX"),
+            EAggregateAccumulator::COUNT => String::from("COUNT"),
+            EAggregateAccumulator::FIRST => String::from("FIRST"),
+            EAggregateAccumulator::LAST => String::from("LAST"),
+            EAggregateAccumulator::PUSH => String::from("PUSH"),
+            EAggregateAccumulator::ADD_TO_SET => String::from("ADD_TO_SET"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EAggregateAccumulator> {
+        match code {
+            "SUM" => Some(EAggregateAccumulator::SUM),
+            "AVG" => Some(EAggregateAccumulator::AVG),
+            "MIN" => Some(EAggregateAccumulator::MIN),
+            "MAX" => Some(EAggregateAccumulator::MAX),
+            "COUNT" => Some(EAggregateAccumulator::COUNT),
+            "FIRST" => Some(EAggregateAccumulator::FIRST),
+            "LAST" => Some(EAggregateAccumulator::LAST),
+            "PUSH" => Some(EAggregateAccumulator::PUSH),
+            "ADD_TO_SET" => Some(EAggregateAccumulator::ADD_TO_SET),
+            _ => None
+        }
+    }
+
+}
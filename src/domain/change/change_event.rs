@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::document::document_data::DocumentData;
+
+use super::e_change_operation::EChangeOperation;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChangeEvent {
+    operation: EChangeOperation,
+    data_base: String,
+    collection: String,
+    document_key: Option<String>,
+    pre_image: Option<DocumentData>,
+    post_image: Option<DocumentData>,
+    updated_fields: Option<String>,
+    removed_fields: Vec<String>,
+    resume_token: String,
+    timestamp: Option<u128>
+}
+
+impl ChangeEvent {
+
+    pub fn new(operation: EChangeOperation, data_base: String, collection: String, document_key: Option<String>, resume_token: String, timestamp: Option<u128>) -> Self {
+        Self {
+            operation, data_base, collection, document_key,
+            pre_image: None, post_image: None,
+            updated_fields: None, removed_fields: Vec::new(),
+            resume_token, timestamp
+        }
+    }
+
+    pub fn set_images(&mut self, pre_image: Option<DocumentData>, post_image: Option<DocumentData>) -> &mut Self {
+        self.pre_image = pre_image;
+        self.post_image = post_image;
+        self
+    }
+
+    pub fn set_update(&mut self, updated_fields: Option<String>, removed_fields: Vec<String>) -> &mut Self {
+        self.updated_fields = updated_fields;
+        self.removed_fields = removed_fields;
+        self
+    }
+
+    pub fn operation(&self) -> EChangeOperation {
+        self.operation
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn collection(&self) -> String {
+        self.collection.clone()
+    }
+
+    pub fn document_key(&self) -> Option<String> {
+        self.document_key.clone()
+    }
+
+    pub fn pre_image(&self) -> Option<DocumentData> {
+        self.pre_image.clone()
+    }
+
+    pub fn post_image(&self) -> Option<DocumentData> {
+        self.post_image.clone()
+    }
+
+    pub fn updated_fields(&self) -> Option<String> {
+        self.updated_fields.clone()
+    }
+
+    pub fn removed_fields(&self) -> Vec<String> {
+        self.removed_fields.clone()
+    }
+
+    pub fn resume_token(&self) -> String {
+        self.resume_token.clone()
+    }
+
+    pub fn timestamp(&self) -> Option<u128> {
+        self.timestamp
+    }
+
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, Deserialize, Serialize)]
+pub enum EChangeOperation {
+    INSERT,
+    UPDATE,
+    REPLACE,
+    DELETE
+}
+
+impl EChangeOperation {
+
+    pub fn items() -> Vec<EChangeOperation> {
+        EChangeOperation::iter().collect()
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EChangeOperation::INSERT => String::from("INSERT"),
+            EChangeOperation::UPDATE => String::from("UPDATE"),
+            EChangeOperation::REPLACE => String::from("REPLACE"),
+            EChangeOperation::DELETE => String::from("DELETE"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EChangeOperation> {
+        match code {
+            "INSERT" => Some(EChangeOperation::INSERT),
+            "UPDATE" => Some(EChangeOperation::UPDATE),
+            "REPLACE" => Some(EChangeOperation::REPLACE),
+            "DELETE" => Some(EChangeOperation::DELETE),
+            _ => None
+        }
+    }
+
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use super::e_change_operation::EChangeOperation;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WatchQuery {
+    data_base: String,
+    collection: Option<String>,
+    #[serde(default)]
+    operations: Vec<EChangeOperation>,
+    #[serde(default)]
+    resume_token: Option<String>,
+    #[serde(default)]
+    sw_pre_image: bool
+}
+
+impl WatchQuery {
+
+    pub fn from_data_base(data_base: String) -> Self {
+        Self::from(data_base, None, Vec::new(), None, false)
+    }
+
+    pub fn from_collection(data_base: String, collection: String) -> Self {
+        Self::from(data_base, Some(collection), Vec::new(), None, false)
+    }
+
+    pub fn from(data_base: String, collection: Option<String>, operations: Vec<EChangeOperation>, resume_token: Option<String>, sw_pre_image: bool) -> Self {
+        Self {
+            data_base, collection, operations, resume_token, sw_pre_image
+        }
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn collection(&self) -> Option<String> {
+        self.collection.clone()
+    }
+
+    pub fn operations(&self) -> Vec<EChangeOperation> {
+        if self.operations.is_empty() {
+            return EChangeOperation::items();
+        }
+        self.operations.clone()
+    }
+
+    pub fn resume_token(&self) -> Option<String> {
+        self.resume_token.clone()
+    }
+
+    pub fn is_pre_image(&self) -> bool {
+        self.sw_pre_image
+    }
+
+    pub fn set_resume_token(&mut self, resume_token: Option<String>) -> &mut Self {
+        self.resume_token = resume_token;
+        self
+    }
+
+    pub fn set_operations(&mut self, operations: Vec<EChangeOperation>) -> &mut Self {
+        self.operations = operations;
+        self
+    }
+
+    pub fn set_pre_image(&mut self, sw_pre_image: bool) -> &mut Self {
+        self.sw_pre_image = sw_pre_image;
+        self
+    }
+
+}
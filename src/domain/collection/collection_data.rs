@@ -1,5 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use crate::domain::document::document_data::DocumentData;
 
+#[derive(Deserialize, Serialize)]
 pub struct CollectionData {
     total: usize,
     limit: Option<usize>,
@@ -1,19 +1,23 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::domain::field::{definition::field_definition::FieldDefinition, generate::field_data::FieldData};
 
-#[derive(Clone, Deserialize)]
+use super::e_collection_category::ECollectionCategory;
+
+#[derive(Clone, Deserialize, Serialize)]
 pub struct CollectionDefinition {
     swrelational: bool,
     definition: Vec<FieldDefinition>,
-    defaults: Vec<FieldData>
+    defaults: Vec<FieldData>,
+    #[serde(default)]
+    categories: Vec<ECollectionCategory>
 }
 
 impl CollectionDefinition {
     
-    pub fn new(swrelational: bool, definition: Vec<FieldDefinition>, defaults: Vec<FieldData>) -> Self {
+    pub fn new(swrelational: bool, definition: Vec<FieldDefinition>, defaults: Vec<FieldData>, categories: Vec<ECollectionCategory>) -> Self {
         Self {
-            swrelational, definition, defaults
+            swrelational, definition, defaults, categories
         }
     }
 
@@ -29,4 +33,8 @@ impl CollectionDefinition {
         self.defaults.clone()
     }
 
+    pub fn categories(&self) -> Vec<ECollectionCategory> {
+        self.categories.clone()
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use super::e_collection_category::ECollectionCategory;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct CollectionOptions {
+    #[serde(default)]
+    category: ECollectionCategory,
+    #[serde(default)]
+    size: Option<u64>,
+    #[serde(default)]
+    max: Option<u64>,
+    #[serde(default)]
+    time_field: Option<String>,
+    #[serde(default)]
+    meta_field: Option<String>,
+    #[serde(default)]
+    granularity: Option<String>,
+    #[serde(default)]
+    expire_after_seconds: Option<u64>,
+    #[serde(default)]
+    collation: Option<String>,
+    #[serde(default)]
+    source: Option<String>,
+    #[serde(default)]
+    pipeline: Option<String>
+}
+
+impl CollectionOptions {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn capped(size: u64, max: Option<u64>) -> Self {
+        Self {
+            category: ECollectionCategory::CAPPED, size: Some(size), max,
+            ..Self::default()
+        }
+    }
+
+    pub fn time_series(time_field: String, meta_field: Option<String>, granularity: Option<String>) -> Self {
+        Self {
+            category: ECollectionCategory::TIME_SERIES, time_field: Some(time_field), meta_field, granularity,
+            ..Self::default()
+        }
+    }
+
+    pub fn clustered() -> Self {
+        Self {
+            category: ECollectionCategory::CLUSTERED,
+            ..Self::default()
+        }
+    }
+
+    pub fn view(source: String, pipeline: String) -> Self {
+        Self {
+            category: ECollectionCategory::VIEW, source: Some(source), pipeline: Some(pipeline),
+            ..Self::default()
+        }
+    }
+
+    pub fn category(&self) -> ECollectionCategory {
+        self.category
+    }
+
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.max
+    }
+
+    pub fn time_field(&self) -> Option<String> {
+        self.time_field.clone()
+    }
+
+    pub fn meta_field(&self) -> Option<String> {
+        self.meta_field.clone()
+    }
+
+    pub fn granularity(&self) -> Option<String> {
+        self.granularity.clone()
+    }
+
+    pub fn expire_after_seconds(&self) -> Option<u64> {
+        self.expire_after_seconds
+    }
+
+    pub fn collation(&self) -> Option<String> {
+        self.collation.clone()
+    }
+
+    pub fn source(&self) -> Option<String> {
+        self.source.clone()
+    }
+
+    pub fn pipeline(&self) -> Option<String> {
+        self.pipeline.clone()
+    }
+
+    pub fn set_expire_after_seconds(&mut self, expire_after_seconds: Option<u64>) -> &mut Self {
+        self.expire_after_seconds = expire_after_seconds;
+        self
+    }
+
+    pub fn set_collation(&mut self, collation: Option<String>) -> &mut Self {
+        self.collation = collation;
+        self
+    }
+
+}
\ No newline at end of file
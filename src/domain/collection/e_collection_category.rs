@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, EnumIter, Deserialize, Serialize)]
+pub enum ECollectionCategory {
+    #[default]
+    STANDARD,
+    CAPPED,
+    TIME_SERIES,
+    CLUSTERED,
+    VIEW
+}
+
+impl ECollectionCategory {
+
+    pub fn items() -> Vec<ECollectionCategory> {
+        ECollectionCategory::iter().collect()
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            ECollectionCategory::STANDARD => String::from("STANDARD"),
+            ECollectionCategory::CAPPED => String::from("CAPPED"),
+            ECollectionCategory::TIME_SERIES => String::from("TIME_SERIES"),
+            ECollectionCategory::CLUSTERED => String::from("CLUSTERED"),
+            ECollectionCategory::VIEW => String::from("VIEW"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<ECollectionCategory> {
+        match code {
+            "STANDARD" => Some(ECollectionCategory::STANDARD),
+            "CAPPED" => Some(ECollectionCategory::CAPPED),
+            "TIME_SERIES" => Some(ECollectionCategory::TIME_SERIES),
+            "CLUSTERED" => Some(ECollectionCategory::CLUSTERED),
+            "VIEW" => Some(ECollectionCategory::VIEW),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -1,10 +1,18 @@
+use serde::{Deserialize, Serialize};
+
 use crate::domain::field::generate::field_data::FieldData;
 
-#[derive(Clone)]
+use super::collection_options::CollectionOptions;
+
+#[derive(Clone, Deserialize, Serialize)]
 pub struct GenerateCollectionQuery {
     data_base: String,
+    #[serde(default)]
     collection: String,
-    fields: Vec<FieldData>
+    #[serde(default)]
+    fields: Vec<FieldData>,
+    #[serde(default)]
+    options: CollectionOptions
 }
 
 impl GenerateCollectionQuery {
@@ -13,7 +21,8 @@ impl GenerateCollectionQuery {
         Self {
             data_base: data_base,
             collection: String::new(),
-            fields: Vec::new()
+            fields: Vec::new(),
+            options: CollectionOptions::new()
         }
     }
 
@@ -21,7 +30,8 @@ impl GenerateCollectionQuery {
         Self {
             data_base: data_base,
             collection: collection,
-            fields: Vec::new()
+            fields: Vec::new(),
+            options: CollectionOptions::new()
         }
     }
 
@@ -29,7 +39,14 @@ impl GenerateCollectionQuery {
         Self {
             data_base: data_base,
             collection: collection,
-            fields: fields
+            fields: fields,
+            options: CollectionOptions::new()
+        }
+    }
+
+    pub fn from_options(data_base: String, collection: String, fields: Vec<FieldData>, options: CollectionOptions) -> Self {
+        Self {
+            data_base, collection, fields, options
         }
     }
 
@@ -45,4 +62,8 @@ impl GenerateCollectionQuery {
         return self.fields.clone();
     }
 
+    pub fn options(&self) -> CollectionOptions {
+        self.options.clone()
+    }
+
 }
\ No newline at end of file
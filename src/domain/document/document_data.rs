@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use super::document_key::DocumentKey;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DocumentData {
     data_base: String,
     collection: String,
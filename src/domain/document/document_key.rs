@@ -1,12 +1,15 @@
+use serde::{Deserialize, Serialize};
+
 use crate::domain::e_json_type::EJSONType;
 
 use super::document_key_attribute::DocumentKeyAttribute;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DocumentKey {
     name: String,
     value: String,
     jtype: EJSONType,
+    #[serde(default)]
     attributes: Vec<DocumentKeyAttribute>
 }
 
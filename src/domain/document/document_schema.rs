@@ -1,5 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use crate::domain::field::generate::field_data::FieldData;
 
+#[derive(Deserialize, Serialize)]
 pub struct DocumentSchema {
     comments: Vec<String>,
     sw_strict: bool,
@@ -1,8 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::field_attribute_default_definition::FieldAttributeDefaultDefinition;
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct FieldAttributeDefinition {
     name: String,
     code: String,
@@ -1,10 +1,10 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::domain::field::e_field_code::EFieldCode;
 
 use super::field_attribute_definition::FieldAttributeDefinition;
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct FieldDefinition {
     order: usize,
     name: String,
@@ -1,10 +1,10 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::domain::field::e_field_code::EFieldCode;
 
 use super::{field_attribute::FieldAttribute, field_reference::FieldReference};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FieldData {
     order: i32,
     code: EFieldCode,
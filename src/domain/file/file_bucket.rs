@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FileBucket {
+    data_base: String,
+    name: String,
+    files: u64,
+    size: u64
+}
+
+impl FileBucket {
+
+    pub fn new(data_base: String, name: String, files: u64, size: u64) -> Self {
+        Self {
+            data_base, name, files, size
+        }
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn files(&self) -> u64 {
+        self.files
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+}
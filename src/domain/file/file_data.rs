@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use super::file_query::FileQuery;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FileData {
+    data_base: String,
+    bucket: String,
+    id: String,
+    filename: String,
+    length: u64,
+    chunk_size: u32,
+    upload_date: String,
+    metadata: Option<String>
+}
+
+impl FileData {
+
+    pub fn new(query: &FileQuery, id: String, filename: String, length: u64, chunk_size: u32, upload_date: String, metadata: Option<String>) -> Self {
+        Self {
+            data_base: query.data_base(), bucket: query.bucket(), id, filename, length,
+            chunk_size, upload_date, metadata
+        }
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn bucket(&self) -> String {
+        self.bucket.clone()
+    }
+
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn filename(&self) -> String {
+        self.filename.clone()
+    }
+
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    pub fn chunk_size(&self) -> u32 {
+        self.chunk_size
+    }
+
+    pub fn upload_date(&self) -> String {
+        self.upload_date.clone()
+    }
+
+    pub fn metadata(&self) -> Option<String> {
+        self.metadata.clone()
+    }
+
+}
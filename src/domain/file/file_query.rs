@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_BUCKET: &str = "fs";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FileQuery {
+    data_base: String,
+    bucket: String,
+    filename: Option<String>
+}
+
+impl FileQuery {
+
+    pub fn from_data_base(data_base: String) -> Self {
+        Self::from(data_base, String::from(DEFAULT_BUCKET))
+    }
+
+    pub fn from(data_base: String, bucket: String) -> Self {
+        Self {
+            data_base, bucket, filename: None
+        }
+    }
+
+    pub fn from_filename(data_base: String, bucket: String, filename: Option<String>) -> Self {
+        Self {
+            data_base, bucket, filename
+        }
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn bucket(&self) -> String {
+        self.bucket.clone()
+    }
+
+    pub fn filename(&self) -> Option<String> {
+        self.filename.clone()
+    }
+
+}
@@ -1,10 +1,10 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::domain::filter::e_filter_category::EFilterCategory;
 
 use super::filter_attribute_default_definition::FilterAttributeDefaultDefinition;
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct FilterAttributeDefinition {
     code: String,
     name: String,
@@ -1,12 +1,20 @@
-use super::filter_element::FilterElement;
+use std::collections::HashMap;
 
-#[derive(Clone)]
+use serde::{Deserialize, Serialize};
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+use super::{document_sort::DocumentSort, filter_element::FilterElement};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DocumentQuery {
     data_base: String,
     collection: String,
     skip: Option<usize>,
     limit: Option<usize>,
-    filter: Option<FilterElement>
+    filter: Option<FilterElement>,
+    #[serde(default)]
+    sort: Vec<DocumentSort>
 }
 
 impl DocumentQuery {
@@ -16,12 +24,12 @@ impl DocumentQuery {
     }
     
     pub fn from(data_base: String, collection: String, skip: Option<usize>, limit: Option<usize>, filter: Option<FilterElement>) -> Self {
+        Self::from_sort(data_base, collection, skip, limit, filter, Vec::new())
+    }
+
+    pub fn from_sort(data_base: String, collection: String, skip: Option<usize>, limit: Option<usize>, filter: Option<FilterElement>, sort: Vec<DocumentSort>) -> Self {
         Self {
-            data_base: data_base,
-            collection: collection,
-            limit: limit,
-            skip: skip,
-            filter: filter
+            data_base, collection, skip, limit, filter, sort
         }
     }
 
@@ -45,4 +53,101 @@ impl DocumentQuery {
         return self.filter.clone();
     }
 
+    pub fn sort(&self) -> Vec<DocumentSort> {
+        return self.sort.clone();
+    }
+
+    pub fn parameters(&self) -> Vec<String> {
+        self.filter.as_ref()
+            .map(|f| f.parameters())
+            .unwrap_or_default()
+    }
+
+    pub fn bind(&self, values: &HashMap<String, String>) -> Result<DocumentQuery, ConnectException> {
+        let missing: Vec<String> = self.parameters().into_iter()
+            .filter(|p| !values.contains_key(p))
+            .collect();
+        if !missing.is_empty() {
+            let message = format!("Missing value for parameters: {}.", missing.join(", "));
+            return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, message));
+        }
+
+        let mut query = self.clone();
+        query.filter = self.filter.as_ref().map(|f| f.bind(values));
+        Ok(query)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use mongodb::bson::{doc, Document};
+
+    use crate::domain::filter::filter_parser::FilterParser;
+
+    use super::*;
+
+    const QUERY: &str = "WHERE status = {{status}} AND (code = {{code}} OR alias = {{code}})";
+
+    fn query() -> DocumentQuery {
+        FilterParser::parse(String::from("app"), String::from("users"), QUERY).unwrap()
+    }
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (String::from(*k), String::from(*v))).collect()
+    }
+
+    fn matches(query: &DocumentQuery) -> Result<Document, ConnectException> {
+        let pipeline = query.filter().unwrap().as_mongo_agregate()?;
+        Ok(pipeline[0].get_document("$match").unwrap().clone())
+    }
+
+    #[test]
+    fn lists_parameters_once() {
+        assert_eq!(query().parameters(), vec![String::from("status"), String::from("code")]);
+        assert!(DocumentQuery::from(String::from("app"), String::from("users"), None, None, None).parameters().is_empty());
+    }
+
+    #[test]
+    fn binds_parameters() {
+        let bound = query().bind(&values(&[("status", "active"), ("code", "'007'")])).unwrap();
+        assert!(bound.parameters().is_empty());
+        assert_eq!(matches(&bound).unwrap(), doc! {"$and": [
+            {"$and": [{"status": "active"}, {"$or": [{"code": "007"}, {"alias": "007"}]}]}
+        ]});
+
+        let bound = query().bind(&values(&[("status", "true"), ("code", "7")])).unwrap();
+        assert_eq!(matches(&bound).unwrap(), doc! {"$and": [
+            {"$and": [{"status": true}, {"$or": [{"code": 7i64}, {"alias": 7i64}]}]}
+        ]});
+    }
+
+    #[test]
+    fn keeps_bound_values_as_single_values() {
+        let bound = query().bind(&values(&[("status", "a' OR '1' = '1"), ("code", "{\"$ne\": null}")])).unwrap();
+        assert_eq!(matches(&bound).unwrap(), doc! {"$and": [
+            {"$and": [{"status": "a' OR '1' = '1"}, {"$or": [{"code": "{\"$ne\": null}"}, {"alias": "{\"$ne\": null}"}]}]}
+        ]});
+    }
+
+    #[test]
+    fn ignores_unused_values() {
+        let bound = query().bind(&values(&[("status", "active"), ("code", "1"), ("unused", "x")])).unwrap();
+        assert!(bound.parameters().is_empty());
+    }
+
+    #[test]
+    fn rejects_missing_values() {
+        let error = query().bind(&values(&[("status", "active")])).unwrap_err();
+        assert_eq!(error.status(), ConnectException::BAD_REQUEST);
+        assert_eq!(error.message(), "Missing value for parameters: code.");
+    }
+
+    #[test]
+    fn rejects_unbound_queries() {
+        let error = matches(&query()).unwrap_err();
+        assert_eq!(error.status(), ConnectException::BAD_REQUEST);
+    }
+
 }
\ No newline at end of file
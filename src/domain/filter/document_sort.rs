@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DocumentSort {
+    field: String,
+    sw_ascending: bool
+}
+
+impl DocumentSort {
+
+    pub fn asc(field: String) -> Self {
+        Self {
+            field, sw_ascending: true
+        }
+    }
+
+    pub fn desc(field: String) -> Self {
+        Self {
+            field, sw_ascending: false
+        }
+    }
+
+    pub fn field(&self) -> String {
+        self.field.clone()
+    }
+
+    pub fn is_ascending(&self) -> bool {
+        self.sw_ascending
+    }
+
+}
\ No newline at end of file
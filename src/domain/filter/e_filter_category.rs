@@ -1,7 +1,7 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use strum::{EnumIter, IntoEnumIterator};
 
-#[derive(Clone, Deserialize, PartialEq, EnumIter)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, EnumIter)]
 pub enum EFilterCategory {
     ID_STRING,
     ID_NUMERIC,
@@ -9,6 +9,7 @@ pub enum EFilterCategory {
     STRING,
     BOOLEAN,
     NUMERIC,
+    PARAMETER,
     COLLECTION,
     ROOT,
 }
@@ -35,6 +36,7 @@ impl EFilterCategory {
             EFilterCategory::STRING => String::from("STRING"),
             EFilterCategory::BOOLEAN => String::from("BOOLEAN"),
             EFilterCategory::NUMERIC => String::from("NUMERIC"),
+            EFilterCategory::PARAMETER => String::from("PARAMETER"),
             EFilterCategory::COLLECTION => String::from("COLLECTION"),
             EFilterCategory::ROOT => String::from("ROOT"),
         }
@@ -48,6 +50,7 @@ impl EFilterCategory {
             "STRING" => Some(EFilterCategory::STRING),
             "BOOLEAN" => Some(EFilterCategory::BOOLEAN),
             "NUMERIC" => Some(EFilterCategory::NUMERIC),
+            "PARAMETER" => Some(EFilterCategory::PARAMETER),
             "COLLECTION" => Some(EFilterCategory::COLLECTION),
             "ROOT" => Some(EFilterCategory::ROOT),
             _ => None
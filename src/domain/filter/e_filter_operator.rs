@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+use super::filter_value_attribute::FilterValueAttribute;
+
+pub const OPERATOR_ATTRIBUTE: &str = "OPERATOR";
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, Deserialize, Serialize)]
+pub enum EFilterOperator {
+    EQ,
+    NE,
+    GT,
+    GTE,
+    LT,
+    LTE,
+    LIKE
+}
+
+impl EFilterOperator {
+
+    pub fn items() -> Vec<EFilterOperator> {
+        EFilterOperator::iter().collect()
+    }
+
+    pub fn as_attribute(&self) -> FilterValueAttribute {
+        FilterValueAttribute::new(String::from(OPERATOR_ATTRIBUTE), self.to_string())
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EFilterOperator::EQ => String::from("EQ"),
+            EFilterOperator::NE => String::from("NE"),
+            EFilterOperator::GT => String::from("GT"),
+            EFilterOperator::GTE => String::from("GTE"),
+            EFilterOperator::LT => String::from("LT"),
+            EFilterOperator::LTE => String::from("LTE"),
+            EFilterOperator::LIKE => String::from("LIKE"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EFilterOperator> {
+        match code {
+            "EQ" => Some(EFilterOperator::EQ),
+            "NE" => Some(EFilterOperator::NE),
+            "GT" => Some(EFilterOperator::GT),
+            "GTE" => Some(EFilterOperator::GTE),
+            "LT" => Some(EFilterOperator::LT),
+            "LTE" => Some(EFilterOperator::LTE),
+            "LIKE" => Some(EFilterOperator::LIKE),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
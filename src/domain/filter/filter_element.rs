@@ -1,10 +1,17 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
 use super::{e_filter_category::EFilterCategory, filter_value_attribute::FilterValueAttribute, filter_value::FilterValue};
 
-#[derive(Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FilterElement {
+    #[serde(default)]
     key: String,
     value: FilterValue,
+    #[serde(default = "FilterElement::default_direction")]
     direction: bool,
+    #[serde(default)]
     negation: bool,
 }
 
@@ -185,4 +192,37 @@ impl FilterElement {
         return self.clone();
     } 
 
+    pub fn parameters(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.collect_parameters(&mut names);
+        return names;
+    }
+
+    fn collect_parameters(&self, names: &mut Vec<String>) {
+        if let Some(name) = self.value.parameter_name() {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        for child in self.value.children() {
+            child.collect_parameters(names);
+        }
+    }
+
+    pub fn bind(&self, values: &HashMap<String, String>) -> FilterElement {
+        let value = match self.value.parameter_name().and_then(|n| values.get(&n)) {
+            Some(bound) => self.value.bind(bound),
+            None => {
+                let children = self.value.children().iter().map(|c| c.bind(values)).collect();
+                FilterValue::from(self.value.category(), self.value.value(), self.value.attributes(), children)
+            },
+        };
+        return FilterElement::from(self.key.clone(), value, self.direction, self.negation);
+    }
+
+    fn default_direction() -> bool {
+        true
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,458 @@
+use crate::commons::{exception::connect_exception::ConnectException, utils};
+
+use super::{
+    document_query::DocumentQuery, document_sort::DocumentSort, e_filter_category::EFilterCategory,
+    e_filter_operator::EFilterOperator, filter_element::FilterElement, filter_value::FilterValue,
+};
+
+#[derive(Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Field(String),
+    Text(String),
+    Number(String),
+    Parameter(String),
+    Symbol(String)
+}
+
+pub struct FilterParser {
+    tokens: Vec<Token>,
+    position: usize
+}
+
+impl FilterParser {
+
+    pub fn parse(data_base: String, collection: String, query: &str) -> Result<DocumentQuery, ConnectException> {
+        let mut parser = FilterParser {
+            tokens: FilterParser::tokenize(query)?,
+            position: 0
+        };
+
+        let mut filter = None;
+        if parser.keyword("WHERE") {
+            let mut root = FilterElement::new();
+            root.push(parser.or()?);
+            filter = Some(root);
+        }
+
+        let mut sort = Vec::new();
+        if parser.keyword("ORDER") {
+            parser.expect_keyword("BY")?;
+            loop {
+                let field = parser.field()?;
+                if parser.keyword("DESC") {
+                    sort.push(DocumentSort::desc(field));
+                } else {
+                    parser.keyword("ASC");
+                    sort.push(DocumentSort::asc(field));
+                }
+                if !parser.symbol(",") {
+                    break;
+                }
+            }
+        }
+
+        let mut limit = None;
+        let mut skip = None;
+        loop {
+            if parser.keyword("LIMIT") {
+                limit = Some(parser.integer()?);
+            } else if parser.keyword("OFFSET") || parser.keyword("SKIP") {
+                skip = Some(parser.integer()?);
+            } else {
+                break;
+            }
+        }
+
+        if let Some(token) = parser.peek() {
+            let message = format!("Unexpected '{}'.", FilterParser::describe(&token));
+            return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, message));
+        }
+
+        Ok(DocumentQuery::from_sort(data_base, collection, skip, limit, filter, sort))
+    }
+
+    fn or(&mut self) -> Result<FilterElement, ConnectException> {
+        let mut elements = vec![self.and()?];
+        while self.keyword("OR") {
+            elements.push(self.and()?);
+        }
+        Ok(FilterParser::group(elements, false))
+    }
+
+    fn and(&mut self) -> Result<FilterElement, ConnectException> {
+        let mut elements = vec![self.unary()?];
+        while self.keyword("AND") {
+            elements.push(self.unary()?);
+        }
+        Ok(FilterParser::group(elements, true))
+    }
+
+    fn unary(&mut self) -> Result<FilterElement, ConnectException> {
+        if self.keyword("NOT") {
+            let mut element = self.unary()?;
+            return Ok(FilterParser::invert(&mut element));
+        }
+
+        if self.symbol("(") {
+            let element = self.or()?;
+            self.expect_symbol(")")?;
+            return Ok(element);
+        }
+
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<FilterElement, ConnectException> {
+        let field = self.field()?;
+
+        let negate = self.keyword("NOT");
+
+        let mut element = if self.keyword("LIKE") {
+            match self.next() {
+                Some(Token::Text(pattern)) => FilterElement::string(field, pattern, vec![EFilterOperator::LIKE.as_attribute()]),
+                _ => return Err(self.error("LIKE expects a quoted pattern")),
+            }
+        } else if self.keyword("IN") {
+            self.expect_symbol("(")?;
+            let mut elements = Vec::new();
+            loop {
+                let mut element = self.value(field.clone(), EFilterOperator::EQ)?;
+                elements.push(element.as_or_ref());
+                if !self.symbol(",") {
+                    break;
+                }
+            }
+            self.expect_symbol(")")?;
+            FilterParser::group(elements, false)
+        } else if negate {
+            return Err(self.error("NOT must be followed by LIKE or IN"));
+        } else {
+            let operator = self.operator()?;
+            self.value(field, operator)?
+        };
+
+        if negate {
+            element.negate();
+        }
+
+        Ok(element)
+    }
+
+    fn operator(&mut self) -> Result<EFilterOperator, ConnectException> {
+        let operator = match self.next() {
+            Some(Token::Symbol(symbol)) => match symbol.as_str() {
+                "=" | "==" => Some(EFilterOperator::EQ),
+                "!=" | "<>" => Some(EFilterOperator::NE),
+                ">" => Some(EFilterOperator::GT),
+                ">=" => Some(EFilterOperator::GTE),
+                "<" => Some(EFilterOperator::LT),
+                "<=" => Some(EFilterOperator::LTE),
+                _ => None,
+            },
+            _ => None,
+        };
+        operator.ok_or_else(|| self.error("Expected a comparison operator"))
+    }
+
+    fn value(&mut self, field: String, operator: EFilterOperator) -> Result<FilterElement, ConnectException> {
+        let attributes = vec![operator.as_attribute()];
+        match self.next() {
+            Some(Token::Text(text)) => Ok(FilterElement::string(field, text, attributes)),
+            Some(Token::Number(number)) => {
+                let value = FilterValue::from(EFilterCategory::NUMERIC, number, attributes, Vec::new());
+                Ok(FilterElement::from(field, value, true, false))
+            },
+            Some(Token::Parameter(parameter)) => {
+                let name = parameter[2..parameter.len() - 2].trim();
+                if !utils::is_placeholder_name(name) {
+                    return Err(self.error(&format!("Invalid parameter name '{}'", name)));
+                }
+                let value = FilterValue::parameter(String::from(name), attributes);
+                Ok(FilterElement::from(field, value, true, false))
+            },
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("TRUE") => Ok(FilterElement::bool(field, true, attributes)),
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("FALSE") => Ok(FilterElement::bool(field, false, attributes)),
+            _ => Err(self.error("Expected a string, number or boolean value")),
+        }
+    }
+
+    fn group(mut elements: Vec<FilterElement>, and: bool) -> FilterElement {
+        if elements.len() == 1 {
+            return elements.remove(0);
+        }
+
+        let children = elements.iter_mut()
+            .map(|e| if and { e.as_and_ref() } else { e.as_or_ref() })
+            .collect();
+
+        FilterElement::from(String::new(), FilterValue::collection(children), true, false)
+    }
+
+    fn invert(element: &mut FilterElement) -> FilterElement {
+        if element.is_negate() {
+            element.affirmate_ref()
+        } else {
+            element.negate_ref()
+        }
+    }
+
+    fn field(&mut self) -> Result<String, ConnectException> {
+        match self.next() {
+            Some(Token::Word(word)) | Some(Token::Field(word)) => Ok(word),
+            _ => Err(self.error("Expected a field name")),
+        }
+    }
+
+    fn integer(&mut self) -> Result<usize, ConnectException> {
+        match self.next() {
+            Some(Token::Number(number)) => number.parse::<usize>()
+                .map_err(|_| self.error(&format!("Invalid count '{}'", number))),
+            _ => Err(self.error("Expected a number")),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.position += 1;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), ConnectException> {
+        if self.keyword(keyword) {
+            return Ok(());
+        }
+        Err(self.error(&format!("Expected '{}'", keyword)))
+    }
+
+    fn symbol(&mut self, symbol: &str) -> bool {
+        if self.peek() == Some(Token::Symbol(String::from(symbol))) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), ConnectException> {
+        if self.symbol(symbol) {
+            return Ok(());
+        }
+        Err(self.error(&format!("Expected '{}'", symbol)))
+    }
+
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.position).cloned()
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek();
+        if token.is_some() {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn error(&self, message: &str) -> ConnectException {
+        let near = match self.tokens.get(self.position.saturating_sub(1)) {
+            Some(token) => format!(" near '{}'", FilterParser::describe(token)),
+            None => String::new(),
+        };
+        ConnectException::from_status(ConnectException::BAD_REQUEST, format!("{}{}.", message, near))
+    }
+
+    fn describe(token: &Token) -> String {
+        match token {
+            Token::Word(value) | Token::Field(value) | Token::Number(value) | Token::Parameter(value) | Token::Symbol(value) => value.clone(),
+            Token::Text(value) => format!("'{}'", value),
+        }
+    }
+
+    fn tokenize(query: &str) -> Result<Vec<Token>, ConnectException> {
+        let chars: Vec<char> = query.chars().collect();
+        let mut tokens = Vec::new();
+
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+
+            if c.is_whitespace() {
+                i += 1;
+                continue;
+            }
+
+            if c == '\'' || c == '"' || c == '`' {
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some(&q) if q == c && chars.get(i + 1) == Some(&c) => {
+                            value.push(c);
+                            i += 2;
+                        },
+                        Some(&q) if q == c => break,
+                        Some(&other) => {
+                            value.push(other);
+                            i += 1;
+                        },
+                        None => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Unterminated quoted value."))),
+                    }
+                }
+                i += 1;
+                tokens.push(if c == '\'' { Token::Text(value) } else { Token::Field(value) });
+                continue;
+            }
+
+            if c == '{' && chars.get(i + 1) == Some(&'{') {
+                let start = i;
+                while i < chars.len() && !(chars[i] == '}' && chars.get(i + 1) == Some(&'}')) {
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Unterminated parameter.")));
+                }
+                i += 2;
+                tokens.push(Token::Parameter(chars[start..i].iter().collect()));
+                continue;
+            }
+
+            let signed = c == '-' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit());
+            if c.is_ascii_digit() || signed {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push(Token::Number(chars[start..i].iter().collect()));
+                continue;
+            }
+
+            if c.is_alphabetic() || c == '_' || c == '$' {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '$' | '.')) {
+                    i += 1;
+                }
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+                continue;
+            }
+
+            let pair: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+            if matches!(pair.as_str(), "==" | "!=" | "<>" | ">=" | "<=") {
+                tokens.push(Token::Symbol(pair));
+                i += 2;
+                continue;
+            }
+
+            if matches!(c, '=' | '<' | '>' | '(' | ')' | ',') {
+                tokens.push(Token::Symbol(c.to_string()));
+                i += 1;
+                continue;
+            }
+
+            return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Unexpected character '{}'.", c)));
+        }
+
+        Ok(tokens)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use mongodb::bson::{doc, Document};
+
+    use super::*;
+
+    fn parse(query: &str) -> Result<DocumentQuery, ConnectException> {
+        FilterParser::parse(String::from("app"), String::from("users"), query)
+    }
+
+    fn matches(query: &str) -> Document {
+        let pipeline = parse(query).unwrap().filter().unwrap().as_mongo_agregate().unwrap();
+        assert_eq!(pipeline.len(), 1);
+        pipeline[0].get_document("$match").unwrap().clone()
+    }
+
+    fn assert_bad_request(query: &str) {
+        match parse(query) {
+            Ok(_) => panic!("Expected an error for '{}'", query),
+            Err(error) => assert_eq!(error.status(), ConnectException::BAD_REQUEST, "{}", query),
+        }
+    }
+
+    #[test]
+    fn binds_and_tighter_than_or() {
+        assert_eq!(matches("WHERE a = 1 OR b = 2 AND c = 3"), doc! {"$and": [
+            {"$or": [{"a": 1i64}, {"$and": [{"b": 2i64}, {"c": 3i64}]}]}
+        ]});
+        assert_eq!(matches("WHERE (a = 1 OR b = 2) AND c = 3"), doc! {"$and": [
+            {"$and": [{"$or": [{"a": 1i64}, {"b": 2i64}]}, {"c": 3i64}]}
+        ]});
+    }
+
+    #[test]
+    fn negates_comparisons_and_groups() {
+        assert_eq!(matches("WHERE NOT a = 1"), doc! {"$and": [{"a": {"$not": {"$eq": 1i64}}}]});
+        assert_eq!(matches("WHERE NOT (a = 1 OR b = 'x')"), doc! {"$and": [
+            {"$nor": [{"$or": [{"a": 1i64}, {"b": "x"}]}]}
+        ]});
+        assert_eq!(matches("WHERE NOT NOT a = 1"), doc! {"$and": [{"a": 1i64}]});
+    }
+
+    #[test]
+    fn parses_in_and_not_in() {
+        assert_eq!(matches("WHERE a IN ('x', 'y')"), doc! {"$and": [{"$or": [{"a": "x"}, {"a": "y"}]}]});
+        assert_eq!(matches("WHERE a NOT IN (1, 2)"), doc! {"$and": [
+            {"$nor": [{"$or": [{"a": 1i64}, {"a": 2i64}]}]}
+        ]});
+        assert_bad_request("WHERE a IN ()");
+        assert_bad_request("WHERE a IN (1, 2");
+        assert_bad_request("WHERE a NOT = 1");
+    }
+
+    #[test]
+    fn escapes_like_patterns() {
+        assert_eq!(matches("WHERE name LIKE 'a.b%_(c)'"), doc! {"$and": [{"name": {"$regex": r"^a\.b.*.\(c\)$"}}]});
+        assert_eq!(matches("WHERE name NOT LIKE 'x%'"), doc! {"$and": [{"name": {"$not": {"$regex": "^x.*$"}}}]});
+        assert_bad_request("WHERE name LIKE 5");
+    }
+
+    #[test]
+    fn parses_values_and_quoted_fields() {
+        assert_eq!(matches("WHERE `my field` >= -2.5 AND \"ok\" = true AND name <> 'it''s'"), doc! {"$and": [
+            {"$and": [{"my field": {"$gte": -2.5}}, {"ok": true}, {"name": {"$ne": "it's"}}]}
+        ]});
+    }
+
+    #[test]
+    fn parses_sort_and_paging() {
+        let query = parse("WHERE a = 1 ORDER BY a DESC, b LIMIT 5 OFFSET 10").unwrap();
+        assert_eq!(query.limit(), Some(5));
+        assert_eq!(query.skip(), Some(10));
+        assert_eq!(query.sort().len(), 2);
+
+        let query = parse("order by a skip 3").unwrap();
+        assert!(query.filter().is_none());
+        assert_eq!(query.skip(), Some(3));
+    }
+
+    #[test]
+    fn rejects_malformed_queries() {
+        assert_bad_request("WHERE name = 'open");
+        assert_bad_request("WHERE `name = 1");
+        assert_bad_request("WHERE a = {{open");
+        assert_bad_request("WHERE a = {{min-age}}");
+        assert_bad_request("WHERE a = 1 b = 2");
+        assert_bad_request("WHERE a = 1 LIMIT 5 extra");
+        assert_bad_request("WHERE a = 1)");
+        assert_bad_request("WHERE a ~ 1");
+        assert_bad_request("WHERE a =");
+        assert_bad_request("WHERE a = 1 LIMIT -1");
+        assert_bad_request("ORDER a");
+    }
+
+}
\ No newline at end of file
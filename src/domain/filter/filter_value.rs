@@ -1,12 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::commons::utils;
+
 use super::{
-    e_filter_category::EFilterCategory, filter_value_attribute::FilterValueAttribute, filter_element::FilterElement
+    e_filter_category::EFilterCategory, e_filter_operator::{EFilterOperator, OPERATOR_ATTRIBUTE}, filter_value_attribute::FilterValueAttribute, filter_element::FilterElement
 };
 
-#[derive(Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FilterValue {
     category: EFilterCategory,
+    #[serde(default)]
     value: String,
+    #[serde(default)]
     attributes: Vec<FilterValueAttribute>,
+    #[serde(default)]
     children: Vec<FilterElement>
 }
 
@@ -56,6 +63,10 @@ impl FilterValue {
         return FilterValue::from_value(EFilterCategory::NUMERIC, value.to_string(), attributes);
     }
 
+    pub fn parameter(name: String, attributes: Vec<FilterValueAttribute>) -> FilterValue {
+        return FilterValue::from_value(EFilterCategory::PARAMETER, format!("{{{{{}}}}}", name), attributes);
+    }
+
     pub fn filter(value: FilterElement) -> FilterValue {
         return FilterValue::collection(Vec::from(vec![value]));
     }
@@ -97,4 +108,80 @@ impl FilterValue {
         return self.children.clone();
     }
 
+    pub fn parameter_name(&self) -> Option<String> {
+        if self.category != EFilterCategory::PARAMETER {
+            return None;
+        }
+
+        self.value.strip_prefix("{{")
+            .and_then(|v| v.strip_suffix("}}"))
+            .filter(|n| utils::is_placeholder_name(n))
+            .map(String::from)
+    }
+
+    pub fn bind(&self, value: &str) -> FilterValue {
+        let quoted = value.len() > 1 && value.starts_with('\'') && value.ends_with('\'');
+        if quoted {
+            return FilterValue::string(String::from(&value[1..value.len() - 1]), self.attributes());
+        }
+
+        if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+            return FilterValue::bool(value.eq_ignore_ascii_case("true"), self.attributes());
+        }
+
+        if value.parse::<f64>().is_ok_and(|n| n.is_finite()) {
+            return FilterValue::from_value(EFilterCategory::NUMERIC, String::from(value), self.attributes());
+        }
+
+        FilterValue::string(String::from(value), self.attributes())
+    }
+
+    pub fn operator(&self) -> EFilterOperator {
+        self.attributes.iter()
+            .find(|a| a.key() == OPERATOR_ATTRIBUTE)
+            .and_then(|a| EFilterOperator::from_string(&a.value()))
+            .unwrap_or(EFilterOperator::EQ)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn bound(value: &str) -> (EFilterCategory, String) {
+        let parameter = FilterValue::parameter(String::from("code"), vec![EFilterOperator::GT.as_attribute()]);
+        let value = parameter.bind(value);
+        assert_eq!(value.operator(), EFilterOperator::GT);
+        (value.category(), value.value())
+    }
+
+    #[test]
+    fn names_parameters_only() {
+        assert_eq!(FilterValue::parameter(String::from("min_age"), Vec::new()).parameter_name(), Some(String::from("min_age")));
+        assert_eq!(FilterValue::string(String::from("{{min_age}}"), Vec::new()).parameter_name(), None);
+    }
+
+    #[test]
+    fn binds_typed_values() {
+        assert_eq!(bound("42"), (EFilterCategory::NUMERIC, String::from("42")));
+        assert_eq!(bound("-1.5"), (EFilterCategory::NUMERIC, String::from("-1.5")));
+        assert_eq!(bound("007"), (EFilterCategory::NUMERIC, String::from("007")));
+        assert_eq!(bound("TRUE"), (EFilterCategory::BOOLEAN, String::from("true")));
+        assert_eq!(bound("false"), (EFilterCategory::BOOLEAN, String::from("false")));
+    }
+
+    #[test]
+    fn binds_quoted_and_other_values_as_strings() {
+        assert_eq!(bound("'007'"), (EFilterCategory::STRING, String::from("007")));
+        assert_eq!(bound("'true'"), (EFilterCategory::STRING, String::from("true")));
+        assert_eq!(bound("''"), (EFilterCategory::STRING, String::new()));
+        assert_eq!(bound("'"), (EFilterCategory::STRING, String::from("'")));
+        assert_eq!(bound("active"), (EFilterCategory::STRING, String::from("active")));
+        assert_eq!(bound("NaN"), (EFilterCategory::STRING, String::from("NaN")));
+        assert_eq!(bound("inf"), (EFilterCategory::STRING, String::from("inf")));
+        assert_eq!(bound("{\"$gt\": 1}"), (EFilterCategory::STRING, String::from("{\"$gt\": 1}")));
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HealthRecord {
+    service: String,
+    timestamp: u128,
+    latency: u128,
+    sw_success: bool,
+    error: Option<String>
+}
+
+impl HealthRecord {
+
+    pub fn success(service: String, timestamp: u128, latency: u128) -> Self {
+        Self {
+            service, timestamp, latency, sw_success: true, error: None
+        }
+    }
+
+    pub fn failure(service: String, timestamp: u128, latency: u128, error: String) -> Self {
+        Self {
+            service, timestamp, latency, sw_success: false, error: Some(error)
+        }
+    }
+
+    pub fn service(&self) -> String {
+        self.service.clone()
+    }
+
+    pub fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+
+    pub fn latency(&self) -> u128 {
+        self.latency
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.sw_success
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+}
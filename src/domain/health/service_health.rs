@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use super::health_record::HealthRecord;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ServiceHealth {
+    service: String,
+    last_success: Option<u128>,
+    last_failure: Option<u128>,
+    last_error: Option<String>,
+    history: Vec<HealthRecord>
+}
+
+impl ServiceHealth {
+
+    pub fn new(service: String) -> Self {
+        Self {
+            service,
+            last_success: None,
+            last_failure: None,
+            last_error: None,
+            history: Vec::new()
+        }
+    }
+
+    pub fn service(&self) -> String {
+        self.service.clone()
+    }
+
+    pub fn last_success(&self) -> Option<u128> {
+        self.last_success
+    }
+
+    pub fn last_failure(&self) -> Option<u128> {
+        self.last_failure
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.clone()
+    }
+
+    pub fn last_record(&self) -> Option<HealthRecord> {
+        self.history.last().cloned()
+    }
+
+    pub fn is_up(&self) -> Option<bool> {
+        self.history.last().map(|r| r.is_success())
+    }
+
+    pub fn latency(&self) -> Option<u128> {
+        self.history.last().map(|r| r.latency())
+    }
+
+    pub fn history(&self) -> Vec<HealthRecord> {
+        self.history.clone()
+    }
+
+    pub fn push(&mut self, record: HealthRecord, capacity: usize) -> &mut Self {
+        match record.is_success() {
+            true => self.last_success = Some(record.timestamp()),
+            false => {
+                self.last_failure = Some(record.timestamp());
+                self.last_error = record.error();
+            },
+        }
+
+        self.history.push(record);
+        if self.history.len() > capacity {
+            let overflow = self.history.len() - capacity;
+            self.history.drain(..overflow);
+        }
+
+        self
+    }
+
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OperationData {
+    id: String,
+    operation: String,
+    namespace: String,
+    client: String,
+    plan_summary: String,
+    duration: u64,
+    description: String
+}
+
+impl OperationData {
+
+    pub fn new(id: String, operation: String, namespace: String, client: String, plan_summary: String, duration: u64, description: String) -> Self {
+        Self {
+            id, operation, namespace, client, plan_summary, duration, description
+        }
+    }
+
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn operation(&self) -> String {
+        self.operation.clone()
+    }
+
+    pub fn namespace(&self) -> String {
+        self.namespace.clone()
+    }
+
+    pub fn client(&self) -> String {
+        self.client.clone()
+    }
+
+    pub fn plan_summary(&self) -> String {
+        self.plan_summary.clone()
+    }
+
+    pub fn duration(&self) -> u64 {
+        self.duration
+    }
+
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+}
\ No newline at end of file
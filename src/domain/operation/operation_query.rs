@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct OperationQuery {
+    namespace: Option<String>,
+    min_seconds: Option<u64>
+}
+
+impl OperationQuery {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from(namespace: Option<String>, min_seconds: Option<u64>) -> Self {
+        Self {
+            namespace, min_seconds
+        }
+    }
+
+    pub fn namespace(&self) -> Option<String> {
+        self.namespace.clone()
+    }
+
+    pub fn min_seconds(&self) -> Option<u64> {
+        self.min_seconds
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter, Deserialize, Serialize)]
+pub enum EServiceRole {
+    VIEWER,
+    EDITOR,
+    ADMIN
+}
+
+impl EServiceRole {
+
+    pub fn items() -> Vec<EServiceRole> {
+        EServiceRole::iter().collect()
+    }
+
+    pub fn allows(&self, required: EServiceRole) -> bool {
+        *self >= required
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EServiceRole::VIEWER => String::from("VIEWER"),
+            EServiceRole::EDITOR => String::from("EDITOR"),
+            EServiceRole::ADMIN => String::from("ADMIN"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EServiceRole> {
+        match code {
+            "VIEWER" => Some(EServiceRole::VIEWER),
+            "EDITOR" => Some(EServiceRole::EDITOR),
+            "ADMIN" => Some(EServiceRole::ADMIN),
+            _ => None
+        }
+    }
+
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use super::{e_service_role::EServiceRole, service_user::ServiceUser};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ServiceGrant {
+    subject: String,
+    sw_group: bool,
+    role: EServiceRole
+}
+
+impl ServiceGrant {
+
+    pub fn user(subject: String, role: EServiceRole) -> Self {
+        Self {
+            subject, sw_group: false, role
+        }
+    }
+
+    pub fn group(subject: String, role: EServiceRole) -> Self {
+        Self {
+            subject, sw_group: true, role
+        }
+    }
+
+    pub fn subject(&self) -> String {
+        self.subject.clone()
+    }
+
+    pub fn is_group(&self) -> bool {
+        self.sw_group
+    }
+
+    pub fn role(&self) -> EServiceRole {
+        self.role
+    }
+
+    pub fn is_same(&self, other: &ServiceGrant) -> bool {
+        self.subject == other.subject && self.sw_group == other.sw_group
+    }
+
+    pub fn applies(&self, user: &ServiceUser) -> bool {
+        match self.sw_group {
+            true => user.groups().contains(&self.subject),
+            false => user.name() == self.subject,
+        }
+    }
+
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ServiceUser {
+    name: String,
+    #[serde(default)]
+    groups: Vec<String>
+}
+
+impl ServiceUser {
+
+    pub fn new(name: String, groups: Vec<String>) -> Self {
+        Self {
+            name, groups
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn groups(&self) -> Vec<String> {
+        self.groups.clone()
+    }
+
+}
\ No newline at end of file
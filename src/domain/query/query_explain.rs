@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{action::generate::action::Action, table::definition::table_definition::TableDefinition};
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct QueryExplain {
+    sw_collection_scan: bool,
+    tables: Vec<TableDefinition>,
+    suggestions: Vec<Action>
+}
+
+impl QueryExplain {
+
+    pub fn new(sw_collection_scan: bool, tables: Vec<TableDefinition>, suggestions: Vec<Action>) -> Self {
+        Self {
+            sw_collection_scan, tables, suggestions
+        }
+    }
+
+    pub fn is_collection_scan(&self) -> bool {
+        self.sw_collection_scan
+    }
+
+    pub fn tables(&self) -> Vec<TableDefinition> {
+        self.tables.clone()
+    }
+
+    pub fn suggestions(&self) -> Vec<Action> {
+        self.suggestions.clone()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::filter::document_query::DocumentQuery;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QueryRecord {
+    service: String,
+    user: String,
+    timestamp: u128,
+    latency: u128,
+    sw_success: bool,
+    error: Option<String>,
+    query: DocumentQuery
+}
+
+impl QueryRecord {
+
+    pub fn success(service: String, user: String, timestamp: u128, latency: u128, query: DocumentQuery) -> Self {
+        Self {
+            service, user, timestamp, latency, sw_success: true, error: None, query
+        }
+    }
+
+    pub fn failure(service: String, user: String, timestamp: u128, latency: u128, query: DocumentQuery, error: String) -> Self {
+        Self {
+            service, user, timestamp, latency, sw_success: false, error: Some(error), query
+        }
+    }
+
+    pub fn service(&self) -> String {
+        self.service.clone()
+    }
+
+    pub fn user(&self) -> String {
+        self.user.clone()
+    }
+
+    pub fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+
+    pub fn latency(&self) -> u128 {
+        self.latency
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.sw_success
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    pub fn query(&self) -> DocumentQuery {
+        self.query.clone()
+    }
+
+}
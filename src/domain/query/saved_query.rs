@@ -0,0 +1,63 @@
+use std::{collections::HashMap, time::{SystemTime, UNIX_EPOCH}};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::filter::document_query::DocumentQuery};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SavedQuery {
+    name: String,
+    owner: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    timestamp: u128,
+    query: DocumentQuery
+}
+
+impl SavedQuery {
+
+    pub fn new(name: String, owner: String, description: String, query: DocumentQuery) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Cannot read actual date.")
+            .as_millis();
+
+        Self {
+            name, owner, description, timestamp, query
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn owner(&self) -> String {
+        self.owner.clone()
+    }
+
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    pub fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+
+    pub fn query(&self) -> DocumentQuery {
+        self.query.clone()
+    }
+
+    pub fn parameters(&self) -> Vec<String> {
+        self.query.parameters()
+    }
+
+    pub fn bind(&self, values: &HashMap<String, String>) -> Result<DocumentQuery, ConnectException> {
+        self.query.bind(values)
+    }
+
+    pub fn is_same(&self, other: &SavedQuery) -> bool {
+        self.name == other.name
+    }
+
+}
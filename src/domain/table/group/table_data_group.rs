@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use super::table_data_field::TableDataField;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TableDataGroup {
     order: usize,
     name: String,
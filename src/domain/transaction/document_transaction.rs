@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::filter::{collection_query::CollectionQuery, document_query::DocumentQuery};
+
+use super::transaction_operation::TransactionOperation;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct DocumentTransaction {
+    operations: Vec<TransactionOperation>
+}
+
+impl DocumentTransaction {
+
+    pub fn new() -> Self {
+        Self {
+            operations: Vec::new()
+        }
+    }
+
+    pub fn from(operations: Vec<TransactionOperation>) -> Self {
+        Self {
+            operations
+        }
+    }
+
+    pub fn operations(&self) -> Vec<TransactionOperation> {
+        self.operations.clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    pub fn push(mut self, operation: TransactionOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    pub fn insert(self, query: CollectionQuery, document: String) -> Self {
+        self.push(TransactionOperation::INSERT { query, document })
+    }
+
+    pub fn update(self, query: DocumentQuery, document: String) -> Self {
+        self.push(TransactionOperation::UPDATE { query, document })
+    }
+
+    pub fn delete(self, query: DocumentQuery) -> Self {
+        self.push(TransactionOperation::DELETE { query })
+    }
+
+    pub fn import(self, query: CollectionQuery, documents: Vec<String>) -> Self {
+        self.push(TransactionOperation::IMPORT { query, documents })
+    }
+
+}
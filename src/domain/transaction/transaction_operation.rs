@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::filter::{collection_query::CollectionQuery, document_query::DocumentQuery};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum TransactionOperation {
+    INSERT {
+        query: CollectionQuery,
+        document: String
+    },
+    UPDATE {
+        query: DocumentQuery,
+        document: String
+    },
+    DELETE {
+        query: DocumentQuery
+    },
+    IMPORT {
+        query: CollectionQuery,
+        documents: Vec<String>
+    }
+}
+
+impl TransactionOperation {
+
+    pub fn code(&self) -> String {
+        match self {
+            TransactionOperation::INSERT { .. } => String::from("INSERT"),
+            TransactionOperation::UPDATE { .. } => String::from("UPDATE"),
+            TransactionOperation::DELETE { .. } => String::from("DELETE"),
+            TransactionOperation::IMPORT { .. } => String::from("IMPORT"),
+        }
+    }
+
+}
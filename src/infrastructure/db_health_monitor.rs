@@ -0,0 +1,143 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::future::join_all;
+use tokio::{sync::broadcast, task::JoinHandle, time};
+
+use crate::{
+    commons::{configuration::configuration::Configuration, exception::connect_exception::ConnectException},
+    domain::health::{health_record::HealthRecord, service_health::ServiceHealth},
+};
+
+use super::db_service::DBService;
+
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+const CHANNEL_CAPACITY: usize = 64;
+
+#[derive(Clone)]
+pub struct DBHealthMonitor {
+    capacity: usize,
+    states: Arc<Mutex<HashMap<String, ServiceHealth>>>,
+    sender: broadcast::Sender<HealthRecord>,
+    handle: Arc<Mutex<Option<JoinHandle<()>>>>
+}
+
+impl DBHealthMonitor {
+
+    pub fn new(capacity: usize) -> DBHealthMonitor {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        DBHealthMonitor {
+            capacity: capacity.max(1),
+            states: Arc::new(Mutex::new(HashMap::new())),
+            sender,
+            handle: Arc::new(Mutex::new(None))
+        }
+    }
+
+    pub fn start(interval: Duration, capacity: usize) -> DBHealthMonitor {
+        let monitor = DBHealthMonitor::new(capacity);
+
+        let worker = monitor.clone();
+        let handle = tokio::spawn(async move {
+            let mut ticker = time::interval(interval);
+            loop {
+                ticker.tick().await;
+                worker.check_all().await;
+            }
+        });
+
+        *monitor.handle.lock().expect("Could not lock mutex") = Some(handle);
+
+        monitor
+    }
+
+    pub fn stop(&self) {
+        if let Some(handle) = self.handle.lock().expect("Could not lock mutex").take() {
+            handle.abort();
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.handle.lock().expect("Could not lock mutex").as_ref()
+            .is_some_and(|h| !h.is_finished())
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<HealthRecord> {
+        self.sender.subscribe()
+    }
+
+    pub fn status(&self, service: &str) -> Option<ServiceHealth> {
+        self.states.lock().expect("Could not lock mutex").get(service).cloned()
+    }
+
+    pub fn statuses(&self) -> Vec<ServiceHealth> {
+        self.states.lock().expect("Could not lock mutex").values().cloned().collect()
+    }
+
+    pub fn history(&self, service: &str) -> Vec<HealthRecord> {
+        self.status(service).map(|s| s.history()).unwrap_or_default()
+    }
+
+    pub async fn check_all(&self) {
+        let services = match Configuration::find_services() {
+            Ok(services) => services,
+            Err(_) => return,
+        };
+
+        let mut checks = Vec::new();
+        for lite in &services {
+            if let Ok(Some(service)) = Configuration::find_service_unchecked(&lite.name()) {
+                checks.push(self.check(service));
+            }
+        }
+
+        join_all(checks).await;
+
+        let names: Vec<String> = services.iter().map(|s| s.name()).collect();
+        self.states.lock().expect("Could not lock mutex")
+            .retain(|name, _| names.contains(name));
+    }
+
+    pub async fn check(&self, service: DBService) -> HealthRecord {
+        let start = Instant::now();
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Cannot read actual date.")
+            .as_millis();
+
+        let result = match time::timeout(CHECK_TIMEOUT, Self::ping(&service)).await {
+            Ok(result) => result,
+            Err(_) => Err(ConnectException::from_status(ConnectException::TIMEOUT, format!("Status check timed out after {} seconds.", CHECK_TIMEOUT.as_secs()))),
+        };
+
+        let latency = start.elapsed().as_millis();
+
+        let record = match result {
+            Ok(()) => HealthRecord::success(service.name(), timestamp, latency),
+            Err(error) => HealthRecord::failure(service.name(), timestamp, latency, error.message()),
+        };
+
+        let changed = {
+            let mut states = self.states.lock().expect("Could not lock mutex");
+            let state = states.entry(service.name())
+                .or_insert_with(|| ServiceHealth::new(service.name()));
+            let changed = state.is_up() != Some(record.is_success());
+            state.push(record.clone(), self.capacity);
+            changed
+        };
+
+        if changed {
+            let _ = self.sender.send(record.clone());
+        }
+
+        record
+    }
+
+    async fn ping(service: &DBService) -> Result<(), ConnectException> {
+        service.instance().await?.status().await
+    }
+
+}
\ No newline at end of file
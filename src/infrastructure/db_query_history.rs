@@ -0,0 +1,113 @@
+use std::{collections::{HashMap, VecDeque}, sync::Mutex};
+
+use lazy_static::lazy_static;
+
+use crate::{commons::{configuration::configuration::Configuration, exception::configuration_exception::ConfigurationException}, domain::query::query_record::QueryRecord};
+
+const HISTORY_FILE: &str = "history.jsonl";
+const HISTORY_CAPACITY: usize = 100;
+
+lazy_static! {
+    static ref REGISTRY: Mutex<HistoryRegistry> = Mutex::new(HistoryRegistry {
+        loaded: false,
+        sw_stale: false,
+        appended: 0,
+        records: HashMap::new()
+    });
+}
+
+struct HistoryRegistry {
+    loaded: bool,
+    sw_stale: bool,
+    appended: usize,
+    records: HashMap<String, VecDeque<QueryRecord>>
+}
+
+pub struct DBQueryHistory {
+}
+
+impl DBQueryHistory {
+
+    pub fn push(record: QueryRecord) {
+        let mut registry = REGISTRY.lock().expect("Could not lock mutex");
+        Self::load(&mut registry);
+
+        let line = serde_json::to_string(&record);
+
+        let records = registry.records.entry(record.service()).or_default();
+        records.push_front(record);
+        records.truncate(HISTORY_CAPACITY);
+
+        // Rewrite after as many appends as the history can keep, so the file stays under twice its size.
+        if registry.sw_stale || registry.appended >= HISTORY_CAPACITY * registry.records.len() {
+            Self::write(&mut registry);
+            return;
+        }
+
+        let result = match line {
+            Ok(line) => Configuration::append_cache_file(HISTORY_FILE, &format!("{}\n", line)),
+            Err(error) => Err(ConfigurationException::new(&error.to_string())),
+        };
+
+        registry.appended += 1;
+        // A failed append leaves the file behind memory, so the next push rewrites it whole.
+        registry.sw_stale = result.is_err();
+    }
+
+    pub fn history(service: &str) -> Vec<QueryRecord> {
+        let mut registry = REGISTRY.lock().expect("Could not lock mutex");
+        Self::load(&mut registry);
+
+        registry.records.get(service)
+            .map(|r| r.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn clear(service: &str) {
+        let mut registry = REGISTRY.lock().expect("Could not lock mutex");
+        Self::load(&mut registry);
+
+        if registry.records.remove(service).is_some() {
+            Self::write(&mut registry);
+        }
+    }
+
+    fn load(registry: &mut HistoryRegistry) {
+        if registry.loaded {
+            return;
+        }
+
+        registry.loaded = true;
+
+        let content = Configuration::read_cache_file(HISTORY_FILE).unwrap_or_default();
+
+        let mut lines = 0;
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            lines += 1;
+            if let Ok(record) = serde_json::from_str::<QueryRecord>(line) {
+                let records = registry.records.entry(record.service()).or_default();
+                records.push_front(record);
+                records.truncate(HISTORY_CAPACITY);
+            }
+        }
+
+        let kept: usize = registry.records.values().map(|r| r.len()).sum();
+        if lines > kept {
+            Self::write(registry);
+        }
+    }
+
+    fn write(registry: &mut HistoryRegistry) {
+        let mut content = String::new();
+        for record in registry.records.values().flat_map(|r| r.iter().rev()) {
+            if let Ok(line) = serde_json::to_string(record) {
+                content.push_str(&line);
+                content.push('\n');
+            }
+        }
+
+        registry.appended = 0;
+        registry.sw_stale = Configuration::write_cache_file(HISTORY_FILE, &content).is_err();
+    }
+
+}
\ No newline at end of file
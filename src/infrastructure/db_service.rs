@@ -9,9 +9,11 @@ use serde::{Deserialize, Serialize};
 
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::{commons::exception::connect_exception::ConnectException, domain::connection_data::ConnectionData, infrastructure::repository::{db_dictionary, i_db_repository::IDBRepository}, service::service::Service};
+use crate::{commons::exception::connect_exception::ConnectException, domain::{connection_data::{self, ConnectionData}, permission::{e_service_role::EServiceRole, service_grant::ServiceGrant, service_user::ServiceUser}, query::saved_query::SavedQuery}, infrastructure::repository::{db_dictionary, i_db_repository::IDBRepository}, service::service::Service};
 
-use super::repository::e_db_repository::EDBRepository;
+use super::{db_session::DBSession, db_session_manager::DBSessionManager, repository::e_db_repository::EDBRepository};
+
+const ANONYMOUS_USER: &str = "anonymous";
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DBService {
@@ -20,7 +22,14 @@ pub struct DBService {
     protected: bool,
     salt: String,
     timestamp: u128,
-    connection_data: ConnectionData
+    #[serde(serialize_with = "connection_data::serialize_with_secret")]
+    connection_data: ConnectionData,
+    #[serde(default)]
+    grants: Vec<ServiceGrant>,
+    #[serde(default)]
+    read_only: bool,
+    #[serde(default)]
+    queries: Vec<SavedQuery>
 }
 
 impl DBService {
@@ -37,7 +46,7 @@ impl DBService {
             .as_millis();
         
         Ok(DBService {
-            name, owner, protected, salt, timestamp, connection_data
+            name, owner, protected, salt, timestamp, connection_data, grants: Vec::new(), read_only: false, queries: Vec::new()
         })
     }
 
@@ -68,12 +77,20 @@ impl DBService {
         
         let result = Argon2::default().verify_password(password.as_bytes(), &parsed_hash.unwrap());
         if result.is_err() {
-            let exception = ConnectException::new(result.unwrap_err().to_string());
+            let exception = ConnectException::from_status(ConnectException::UNAUTHORIZED, result.unwrap_err().to_string());
             return Err(exception);
         }
 
         Ok(())
     }
+
+    pub fn authorize(&self, user: &ServiceUser, password: String) -> Result<DBSession, ConnectException> {
+        DBSessionManager::authorize(self, user, password)
+    }
+
+    pub fn is_authorized_session(&self, token: &str) -> Result<DBSession, ConnectException> {
+        DBSessionManager::validate(self, token)
+    }
     
     pub fn name(&self) -> String {
         self.name.clone()
@@ -103,13 +120,114 @@ impl DBService {
         self.connection_data.clone()
     }
 
+    pub fn is_read_only(&self) -> bool {
+        self.read_only || self.connection_data.is_read_only()
+    }
+
+    pub fn set_read_only(&mut self, read_only: bool) -> &mut Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn grants(&self) -> Vec<ServiceGrant> {
+        self.grants.clone()
+    }
+
     pub fn is_same(&self, other: DBService) -> bool {
         self.name == other.name
     }
 
+    pub fn role(&self, user: &ServiceUser) -> Option<EServiceRole> {
+        if user.name() == self.owner {
+            return Some(EServiceRole::ADMIN);
+        }
+
+        self.grants.iter()
+            .filter(|g| g.applies(user))
+            .map(|g| g.role())
+            .max()
+    }
+
+    pub fn grant(&mut self, actor: &ServiceUser, grant: ServiceGrant) -> Result<(), ConnectException> {
+        self.check_role(actor, EServiceRole::ADMIN)?;
+
+        self.grants.retain(|g| !g.is_same(&grant));
+        self.grants.push(grant);
+
+        Ok(())
+    }
+
+    pub fn revoke(&mut self, actor: &ServiceUser, grant: &ServiceGrant) -> Result<Option<ServiceGrant>, ConnectException> {
+        self.check_role(actor, EServiceRole::ADMIN)?;
+
+        let position = self.grants.iter().position(|g| g.is_same(grant));
+        Ok(position.map(|p| self.grants.remove(p)))
+    }
+
+    pub fn queries(&self) -> Vec<SavedQuery> {
+        self.queries.clone()
+    }
+
+    pub fn saved_query(&self, name: &str) -> Option<SavedQuery> {
+        self.queries.iter().find(|q| q.name() == name).cloned()
+    }
+
+    pub fn save_query(&mut self, actor: &ServiceUser, query: SavedQuery) -> Result<Option<SavedQuery>, ConnectException> {
+        let role = self.check_role(actor, EServiceRole::VIEWER)?;
+
+        let position = self.queries.iter().position(|q| q.is_same(&query));
+        if let Some(current) = position.map(|p| &self.queries[p]) {
+            if current.owner() != actor.name() && !role.allows(EServiceRole::ADMIN) {
+                return Err(ConnectException::from_status(ConnectException::CONFLICT, format!("Query '{}' already exists and belongs to '{}'.", current.name(), current.owner())));
+            }
+        }
+
+        let previous = position.map(|p| self.queries.remove(p));
+        self.queries.push(query);
+
+        Ok(previous)
+    }
+
+    pub fn remove_query(&mut self, actor: &ServiceUser, name: &str) -> Result<Option<SavedQuery>, ConnectException> {
+        let role = self.check_role(actor, EServiceRole::VIEWER)?;
+
+        let position = match self.queries.iter().position(|q| q.name() == name) {
+            Some(position) => position,
+            None => return Ok(None),
+        };
+
+        if self.queries[position].owner() != actor.name() && !role.allows(EServiceRole::ADMIN) {
+            return Err(ConnectException::from_status(ConnectException::FORBIDDEN, format!("Permission denied: query '{}' belongs to '{}'.", name, self.queries[position].owner())));
+        }
+
+        Ok(Some(self.queries.remove(position)))
+    }
+
+    pub fn check_role(&self, user: &ServiceUser, required: EServiceRole) -> Result<EServiceRole, ConnectException> {
+        match self.role(user) {
+            Some(role) if role.allows(required) => Ok(role),
+            _ => Err(ConnectException::from_status(ConnectException::FORBIDDEN, format!("Permission denied: '{}' role is required on service '{}'.", required.to_string(), self.name))),
+        }
+    }
+
+    /// Read-only view with the viewer role, not tied to any user. Use `instance_as` to act as a user.
     pub async fn instance(&self) -> Result<Service<impl IDBRepository>, ConnectException> {
         let repository = db_dictionary::find(&self.connection_data).await?;
-        Ok(Service::from(repository))
+        Ok(self.guard(Service::from(repository), String::from(ANONYMOUS_USER)))
+    }
+
+    pub async fn instance_as(&self, user: &ServiceUser) -> Result<Service<impl IDBRepository>, ConnectException> {
+        let role = self.check_role(user, EServiceRole::VIEWER)?;
+        let repository = db_dictionary::find(&self.connection_data).await?;
+        Ok(self.guard(Service::from_role(repository, role), user.name()))
+    }
+
+    fn guard<T: IDBRepository>(&self, service: Service<T>, user: String) -> Service<T> {
+        let service = service.as_recorded(self.name(), user);
+        match self.is_read_only() {
+            true => service.as_read_only(),
+            false => service,
+        }
     }
 
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use super::repository::e_db_repository::EDBRepository;
 
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct DBServiceLite {
     name: String,
     category: EDBRepository,
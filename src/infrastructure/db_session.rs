@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::permission::service_user::ServiceUser;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DBSession {
+    token: String,
+    service: String,
+    user: ServiceUser,
+    timestamp: u128,
+    expires: u128
+}
+
+impl DBSession {
+
+    pub fn new(token: String, service: String, user: ServiceUser, timestamp: u128, expires: u128) -> DBSession {
+        DBSession {
+            token, service, user, timestamp, expires
+        }
+    }
+
+    pub fn token(&self) -> String {
+        self.token.clone()
+    }
+
+    pub fn service(&self) -> String {
+        self.service.clone()
+    }
+
+    pub fn user(&self) -> ServiceUser {
+        self.user.clone()
+    }
+
+    pub fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+
+    pub fn expires(&self) -> u128 {
+        self.expires
+    }
+
+    pub fn is_expired(&self, now: u128) -> bool {
+        now >= self.expires
+    }
+
+}
\ No newline at end of file
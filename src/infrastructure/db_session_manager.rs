@@ -0,0 +1,132 @@
+use std::{collections::HashMap, sync::Mutex, time::{SystemTime, UNIX_EPOCH}};
+
+use lazy_static::lazy_static;
+use uuid::Uuid;
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::permission::service_user::ServiceUser};
+
+use super::{db_service::DBService, db_session::DBSession};
+
+const SESSION_LIFETIME: u128 = 30 * 60 * 1000;
+const FREE_ATTEMPTS: u32 = 3;
+const LOCKOUT_BASE: u128 = 1000;
+const LOCKOUT_MAX: u128 = 15 * 60 * 1000;
+const LOCKOUT_EXPONENT_MAX: u32 = 20;
+
+lazy_static! {
+    static ref REGISTRY: Mutex<SessionRegistry> = Mutex::new(SessionRegistry {
+        sessions: HashMap::new(),
+        attempts: HashMap::new()
+    });
+}
+
+struct SessionRegistry {
+    sessions: HashMap<String, DBSession>,
+    attempts: HashMap<(String, String), FailedAttempts>
+}
+
+struct FailedAttempts {
+    failures: u32,
+    locked_until: u128
+}
+
+pub struct DBSessionManager {
+}
+
+impl DBSessionManager {
+
+    pub fn authorize(service: &DBService, user: &ServiceUser, password: String) -> Result<DBSession, ConnectException> {
+        let now = Self::now();
+        // Attempts are counted per user, so one client's guesses cannot lock everyone else out.
+        let key = (service.name(), user.name());
+
+        {
+            let mut registry = REGISTRY.lock().expect("Could not lock mutex");
+            let attempts = registry.attempts.entry(key.clone())
+                .or_insert(FailedAttempts { failures: 0, locked_until: 0 });
+            if attempts.locked_until > now {
+                let seconds = (attempts.locked_until - now).div_ceil(1000);
+                return Err(ConnectException::from_status(ConnectException::TOO_MANY_REQUESTS, format!("Service is locked after repeated failed attempts. Retry in {} seconds.", seconds)));
+            }
+
+            // The attempt is counted as failed before verifying, so concurrent guesses cannot outrun the lockout.
+            attempts.failures += 1;
+            if attempts.failures >= FREE_ATTEMPTS {
+                let exponent = attempts.failures - FREE_ATTEMPTS;
+                let lockout = (LOCKOUT_BASE * 2u128.pow(exponent.min(LOCKOUT_EXPONENT_MAX)))
+                    .min(LOCKOUT_MAX);
+                attempts.locked_until = now + lockout;
+            }
+        }
+
+        service.is_authorized(password)?;
+
+        let mut registry = REGISTRY.lock().expect("Could not lock mutex");
+
+        registry.attempts.remove(&key);
+        registry.sessions.retain(|_, s| !s.is_expired(now));
+
+        let session = DBSession::new(
+            Uuid::new_v4().to_string(),
+            service.name(),
+            user.clone(),
+            now,
+            now + SESSION_LIFETIME
+        );
+
+        registry.sessions.insert(session.token(), session.clone());
+
+        Ok(session)
+    }
+
+    pub fn validate(service: &DBService, token: &str) -> Result<DBSession, ConnectException> {
+        let now = Self::now();
+        let mut registry = REGISTRY.lock().expect("Could not lock mutex");
+
+        let session = match registry.sessions.get(token) {
+            Some(session) => session.clone(),
+            None => return Err(ConnectException::from_status(ConnectException::UNAUTHORIZED, String::from("Session not found."))),
+        };
+
+        if session.is_expired(now) {
+            registry.sessions.remove(token);
+            return Err(ConnectException::from_status(ConnectException::UNAUTHORIZED, String::from("Session has expired.")));
+        }
+
+        if session.service() != service.name() {
+            return Err(ConnectException::from_status(ConnectException::FORBIDDEN, String::from("Session does not belong to this service.")));
+        }
+
+        Ok(session)
+    }
+
+    pub fn revoke(token: &str) -> Option<DBSession> {
+        let mut registry = REGISTRY.lock().expect("Could not lock mutex");
+        registry.sessions.remove(token)
+    }
+
+    pub fn revoke_service(service: &str) -> usize {
+        let mut registry = REGISTRY.lock().expect("Could not lock mutex");
+        let before = registry.sessions.len();
+        registry.sessions.retain(|_, s| s.service() != service);
+        registry.attempts.retain(|(s, _), _| s != service);
+        before - registry.sessions.len()
+    }
+
+    pub fn find_sessions(service: &str) -> Vec<DBSession> {
+        let now = Self::now();
+        let registry = REGISTRY.lock().expect("Could not lock mutex");
+        registry.sessions.values()
+            .filter(|s| s.service() == service && !s.is_expired(now))
+            .cloned()
+            .collect()
+    }
+
+    fn now() -> u128 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Cannot read actual date.")
+            .as_millis()
+    }
+
+}
\ No newline at end of file
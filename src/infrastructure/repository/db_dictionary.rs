@@ -1,9 +1,33 @@
 use crate::{commons::exception::connect_exception::ConnectException, domain::connection_data::ConnectionData};
 
-use super::{e_db_repository::EDBRepository, i_db_repository::IDBRepository, mongo_db::mongo_db_repository::MongoDbRepository};
+use super::{e_db_repository::EDBRepository, i_db_repository::IDBRepository, mongo_db::{mongo_db_connection, mongo_db_repository::MongoDbRepository}};
 
 pub async fn find(connection: &ConnectionData) -> Result<impl IDBRepository, ConnectException>  {
     match connection.category() {
         EDBRepository::MongoDB => Ok(MongoDbRepository::new(connection).await?)
     }
+}
+
+pub fn parse_connection(category: EDBRepository, connection: &str) -> Result<ConnectionData, ConnectException> {
+    match category {
+        EDBRepository::MongoDB => mongo_db_connection::parse(connection)
+    }
+}
+
+pub fn format_connection(connection: &ConnectionData, mask: bool) -> String {
+    match connection.category() {
+        EDBRepository::MongoDB => mongo_db_connection::format(connection, mask)
+    }
+}
+
+pub fn mask_connection_option(category: &EDBRepository, key: &str, value: &str) -> String {
+    match category {
+        EDBRepository::MongoDB => mongo_db_connection::mask_option(key, value)
+    }
+}
+
+pub fn validate_connection(connection: &ConnectionData) -> Result<(), ConnectException> {
+    match connection.category() {
+        EDBRepository::MongoDB => mongo_db_connection::validate(connection)
+    }
 }
\ No newline at end of file
@@ -1,28 +1,43 @@
 use async_trait::async_trait;
+use futures_util::{io::{AsyncRead, AsyncWrite}, stream::BoxStream};
 
 use crate::{
     commons::exception::connect_exception::ConnectException,
     domain::{
-        action::{definition::action_definition::ActionDefinition, generate::action::Action}, collection::{
+        aggregate::aggregate_query::AggregateQuery,
+        action::{definition::action_definition::ActionDefinition, generate::action::Action},
+        change::{change_event::ChangeEvent, watch_query::WatchQuery}, collection::{
             collection_data::CollectionData, collection_definition::CollectionDefinition,
             generate_collection_query::GenerateCollectionQuery,
-        }, data_base::generate_database_query::GenerateDatabaseQuery, document::{document_data::DocumentData, document_schema::DocumentSchema}, filter::{
+        }, data_base::generate_database_query::GenerateDatabaseQuery, document::{document_data::DocumentData, document_schema::DocumentSchema},
+        file::{file_bucket::FileBucket, file_data::FileData, file_query::FileQuery}, operation::{operation_data::OperationData, operation_query::OperationQuery}, filter::{
             collection_query::CollectionQuery, data_base_query::DataBaseQuery,
             definition::filter_definition::FilterDefinition, document_query::DocumentQuery,
-        }, table::{definition::table_definition::TableDefinition, group::table_data_group::TableDataGroup}
+        }, query::query_explain::QueryExplain, table::{definition::table_definition::TableDefinition, group::table_data_group::TableDataGroup}
     },
 };
 
+use super::i_db_transaction::IDBTransaction;
+
 #[async_trait]
 pub trait IDBRepository: Clone + Send + Sync {
+    fn is_read_only(&self) -> bool;
     async fn status(&self) -> Result<(), ConnectException>;
     async fn metadata(&self) -> Result<Vec<TableDataGroup>, ConnectException>;
+    async fn actions(&self) -> Result<Vec<ActionDefinition>, ConnectException>;
+    async fn action(&self, code: &str) -> Result<Option<ActionDefinition>, ConnectException>;
+    async fn execute_action(&self, action: &Action) -> Result<String, ConnectException>;
+    async fn operations(&self, query: &OperationQuery) -> Result<Vec<OperationData>, ConnectException>;
 
     async fn data_base_metadata(&self, query: &DataBaseQuery) -> Result<Vec<TableDataGroup>, ConnectException>;
     async fn data_base_find_all(&self) -> Result<Vec<String>, ConnectException>;
     async fn data_base_exists(&self, query: &DataBaseQuery) -> Result<bool, ConnectException>;
     async fn data_base_create(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException>;
     async fn data_base_drop(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException>;
+    async fn data_base_actions(&self, query: &DataBaseQuery) -> Result<Vec<ActionDefinition>, ConnectException>;
+    async fn data_base_action(&self, query: &DataBaseQuery, code: &str) -> Result<Option<ActionDefinition>, ConnectException>;
+    async fn data_base_execute_action(&self, query: &DataBaseQuery, action: &Action) -> Result<String, ConnectException>;
+    async fn data_base_profile(&self, query: &DataBaseQuery) -> Result<TableDefinition, ConnectException>;
 
     async fn collection_accept_schema(&self) -> Result<CollectionDefinition, ConnectException>;
     async fn collection_metadata(&self, query: &CollectionQuery) -> Result<Vec<TableDataGroup>, ConnectException>;
@@ -30,6 +45,8 @@ pub trait IDBRepository: Clone + Send + Sync {
     async fn collection_actions(&self, query: &CollectionQuery) -> Result<Vec<ActionDefinition>, ConnectException>;
     async fn collection_action(&self, query: &CollectionQuery, code: &String) -> Result<Option<ActionDefinition>, ConnectException>;
     async fn collection_execute_action(&self, query: &CollectionQuery, action: &Action) -> Result<String, ConnectException>;
+    async fn collection_validate(&self, query: &CollectionQuery, sw_full: bool) -> Result<TableDefinition, ConnectException>;
+    async fn collection_storage(&self, query: &CollectionQuery) -> Result<TableDefinition, ConnectException>;
     async fn collection_find_all(&self, query: &DataBaseQuery) -> Result<Vec<String>, ConnectException>;
     async fn collection_exists(&self, query: &CollectionQuery) -> Result<bool, ConnectException>;
     async fn collection_create(&self, query: &GenerateCollectionQuery) -> Result<String, ConnectException>;
@@ -37,13 +54,25 @@ pub trait IDBRepository: Clone + Send + Sync {
     async fn collection_rename(&self, query: &CollectionQuery, name: &str) -> Result<String, ConnectException>;
     async fn collection_export(&self, query: &CollectionQuery) -> Result<Vec<DocumentData>, ConnectException>;
     async fn collection_import(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<String, ConnectException>;
+
+    async fn file_buckets(&self, query: &DataBaseQuery) -> Result<Vec<FileBucket>, ConnectException>;
+    async fn file_find_all(&self, query: &FileQuery) -> Result<Vec<FileData>, ConnectException>;
+    async fn file_upload(&self, query: &FileQuery, filename: &str, metadata: Option<&str>, source: &mut (dyn AsyncRead + Unpin + Send)) -> Result<FileData, ConnectException>;
+    async fn file_download(&self, query: &FileQuery, id: &str, destination: &mut (dyn AsyncWrite + Unpin + Send)) -> Result<(), ConnectException>;
+    async fn file_delete(&self, query: &FileQuery, id: &str) -> Result<String, ConnectException>;
+    async fn file_rename(&self, query: &FileQuery, id: &str, filename: &str) -> Result<String, ConnectException>;
     
     async fn filter_schema(&self) -> Result<FilterDefinition, ConnectException>;
+    async fn parse_query(&self, data_base: &str, query: &str) -> Result<DocumentQuery, ConnectException>;
     async fn find_all(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException>;
     async fn find_query(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException>;
     async fn find(&self, query: &DocumentQuery) -> Result<Option<DocumentData>, ConnectException>;
+    async fn explain(&self, query: &DocumentQuery) -> Result<QueryExplain, ConnectException>;
+    async fn aggregate(&self, query: &AggregateQuery) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn watch(&self, query: &WatchQuery) -> Result<BoxStream<'static, Result<ChangeEvent, ConnectException>>, ConnectException>;
     async fn schema(&self, query: &CollectionQuery) -> Result<DocumentSchema, ConnectException>;
     async fn insert(&self, query: &CollectionQuery, value: &str) -> Result<DocumentData, ConnectException>;
     async fn update(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException>;
     async fn delete(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn begin(&self) -> Result<Box<dyn IDBTransaction>, ConnectException>;
 }
\ No newline at end of file
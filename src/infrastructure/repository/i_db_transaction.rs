@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        document::document_data::DocumentData,
+        filter::{collection_query::CollectionQuery, document_query::DocumentQuery},
+        transaction::transaction_operation::TransactionOperation,
+    },
+};
+
+#[async_trait]
+pub trait IDBTransaction: Send {
+    async fn insert(&mut self, query: &CollectionQuery, value: &str) -> Result<DocumentData, ConnectException>;
+    async fn update(&mut self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn delete(&mut self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn collection_import(&mut self, query: &CollectionQuery, documents: Vec<String>) -> Result<String, ConnectException>;
+    async fn commit(&mut self) -> Result<(), ConnectException>;
+    async fn abort(&mut self) -> Result<(), ConnectException>;
+
+    async fn execute(&mut self, operation: &TransactionOperation) -> Result<Vec<DocumentData>, ConnectException> {
+        match operation {
+            TransactionOperation::INSERT { query, document } => Ok(vec![self.insert(query, document).await?]),
+            TransactionOperation::UPDATE { query, document } => self.update(query, document).await,
+            TransactionOperation::DELETE { query } => self.delete(query).await,
+            TransactionOperation::IMPORT { query, documents } => {
+                self.collection_import(query, documents.clone()).await?;
+                Ok(Vec::new())
+            },
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,258 @@
+use mongodb::bson::{Bson, Document};
+
+use crate::domain::{
+    action::generate::{action::Action, action_form::ActionForm, form_field::FormField},
+    filter::document_query::DocumentQuery,
+    query::query_explain::QueryExplain,
+    table::definition::{table_definition::TableDefinition, table_row_definition::TableRowDefinition},
+};
+
+use super::mongo_db_actions::{ACTION_INDEXES_NEW, FIELD_DIRECTION, FIELD_FIELD, FIELD_NAME, FIELD_UNIQUE, FORM_ATTRIBUTES, FORM_FIELDS};
+
+const STAGE_COLLECTION_SCAN: &str = "COLLSCAN";
+
+pub(crate) struct ExtractorExplainMongoDb {
+}
+
+impl ExtractorExplainMongoDb {
+
+    pub(crate) fn from_explain(explain: &Document, query: &DocumentQuery) -> QueryExplain {
+        let (planner, stats) = Self::explain_root(explain);
+
+        let winning_plan = planner.as_ref()
+            .and_then(|p| p.get_document("winningPlan").ok())
+            .map(|p| p.get_document("queryPlan").unwrap_or(p).clone());
+
+        let mut stages = Vec::new();
+        if let Some(plan) = &winning_plan {
+            Self::flatten(plan, 0, &mut stages);
+        }
+
+        let collection_scan = stages.iter().any(|(_, s)| text(s, "stage") == STAGE_COLLECTION_SCAN);
+
+        let mut tables = vec![
+            Self::summary(&stages, stats.as_ref()),
+            Self::stages(&stages),
+        ];
+
+        let mut suggestions = Vec::new();
+        if collection_scan {
+            let filter = stages.iter()
+                .find(|(_, s)| text(s, "stage") == STAGE_COLLECTION_SCAN)
+                .and_then(|(_, s)| s.get_document("filter").ok().cloned())
+                .unwrap_or_default();
+
+            if let Some(action) = Self::suggest(&filter, query) {
+                tables.push(Self::suggestions(&action));
+                suggestions.push(action);
+            }
+        }
+
+        QueryExplain::new(collection_scan, tables, suggestions)
+    }
+
+    fn explain_root(explain: &Document) -> (Option<Document>, Option<Document>) {
+        if explain.contains_key("queryPlanner") {
+            return (
+                explain.get_document("queryPlanner").ok().cloned(),
+                explain.get_document("executionStats").ok().cloned()
+            );
+        }
+
+        let cursor = explain.get_array("stages").ok()
+            .and_then(|s| s.first())
+            .and_then(|s| s.as_document())
+            .and_then(|s| s.get_document("$cursor").ok());
+
+        match cursor {
+            Some(cursor) => (
+                cursor.get_document("queryPlanner").ok().cloned(),
+                cursor.get_document("executionStats").ok().cloned()
+            ),
+            None => (None, None),
+        }
+    }
+
+    fn flatten(stage: &Document, depth: usize, stages: &mut Vec<(usize, Document)>) {
+        stages.push((depth, stage.clone()));
+
+        if let Ok(input) = stage.get_document("inputStage") {
+            Self::flatten(input, depth + 1, stages);
+        }
+
+        if let Ok(inputs) = stage.get_array("inputStages") {
+            for input in inputs.iter().filter_map(|i| i.as_document()) {
+                Self::flatten(input, depth + 1, stages);
+            }
+        }
+    }
+
+    fn summary(stages: &[(usize, Document)], stats: Option<&Document>) -> TableDefinition {
+        let mut table = TableDefinition::new(String::from("Summary"));
+
+        let mut titles = TableRowDefinition::new();
+        titles.push_title(String::from("Winning plan"));
+        titles.push_title(String::from("Index"));
+        titles.push_title(String::from("Returned"));
+        titles.push_title(String::from("Docs examined"));
+        titles.push_title(String::from("Keys examined"));
+        titles.push_title(String::from("Time (ms)"));
+
+        let plan: Vec<String> = stages.iter().map(|(_, s)| text(s, "stage")).collect();
+        let indexes: Vec<String> = stages.iter()
+            .map(|(_, s)| text(s, "indexName"))
+            .filter(|i| !i.is_empty())
+            .collect();
+
+        let mut row = TableRowDefinition::new();
+        row.push(plan.join(" <- "));
+        row.push(indexes.join(", "));
+        row.push(stats.map(|s| text(s, "nReturned")).unwrap_or_default());
+        row.push(stats.map(|s| text(s, "totalDocsExamined")).unwrap_or_default());
+        row.push(stats.map(|s| text(s, "totalKeysExamined")).unwrap_or_default());
+        row.push(stats.map(|s| text(s, "executionTimeMillis")).unwrap_or_default());
+
+        table.push(titles);
+        table.push(row);
+
+        table
+    }
+
+    fn stages(stages: &[(usize, Document)]) -> TableDefinition {
+        let mut table = TableDefinition::new(String::from("Stages"));
+
+        if stages.is_empty() {
+            return table;
+        }
+
+        let mut titles = TableRowDefinition::new();
+        titles.push_title(String::from("Stage"));
+        titles.push_title(String::from("Index"));
+        titles.push_title(String::from("Keys"));
+        titles.push_title(String::from("Direction"));
+        titles.push_title(String::from("Filter"));
+        table.push(titles);
+
+        for (depth, stage) in stages {
+            let mut row = TableRowDefinition::new();
+            row.push(format!("{}{}", "  ".repeat(*depth), text(stage, "stage")));
+            row.push(text(stage, "indexName"));
+            row.push(text(stage, "keyPattern"));
+            row.push(text(stage, "direction"));
+            row.push(text(stage, "filter"));
+            table.push(row);
+        }
+
+        table
+    }
+
+    fn suggestions(action: &Action) -> TableDefinition {
+        let mut table = TableDefinition::new(String::from("Suggested indexes"));
+
+        let mut titles = TableRowDefinition::new();
+        titles.push_title(String::from("Index"));
+        titles.push_title(String::from("Reason"));
+        table.push(titles);
+
+        let name = action.find_form(String::from(FORM_ATTRIBUTES))
+            .and_then(|f| f.find_fields(String::from(FIELD_NAME)).first().map(|f| f.value()))
+            .unwrap_or_default();
+
+        let mut row = TableRowDefinition::new();
+        row.push(name);
+        row.push(String::from("The query scans the whole collection. Equality fields come first, then sort fields, then range fields."));
+        table.push(row);
+
+        table
+    }
+
+    fn suggest(filter: &Document, query: &DocumentQuery) -> Option<Action> {
+        let mut equality = Vec::new();
+        let mut range = Vec::new();
+        Self::collect_fields(filter, &mut equality, &mut range);
+
+        let mut keys: Vec<(String, i32)> = Vec::new();
+        let mut push = |field: String, direction: i32| {
+            if !keys.iter().any(|(f, _)| *f == field) {
+                keys.push((field, direction));
+            }
+        };
+
+        for field in equality {
+            push(field, 1);
+        }
+
+        for sort in query.sort() {
+            push(sort.field(), if sort.is_ascending() { 1 } else { -1 });
+        }
+
+        for field in range {
+            push(field, 1);
+        }
+
+        if keys.is_empty() {
+            return None;
+        }
+
+        let name = keys.iter()
+            .map(|(f, d)| format!("{}_{}", f, d))
+            .collect::<Vec<String>>()
+            .join("_");
+
+        let fields = keys.into_iter()
+            .map(|(f, d)| vec![
+                FormField::new(String::from(FIELD_FIELD), f),
+                FormField::new(String::from(FIELD_DIRECTION), d.to_string())
+            ])
+            .collect();
+
+        let attributes = vec![vec![
+            FormField::new(String::from(FIELD_NAME), name),
+            FormField::new(String::from(FIELD_UNIQUE), String::from("false"))
+        ]];
+
+        Some(Action::new(String::from(ACTION_INDEXES_NEW), vec![
+            ActionForm::new(String::from(FORM_FIELDS), fields),
+            ActionForm::new(String::from(FORM_ATTRIBUTES), attributes)
+        ]))
+    }
+
+    fn collect_fields(filter: &Document, equality: &mut Vec<String>, range: &mut Vec<String>) {
+        for (key, value) in filter {
+            if key == "$and" {
+                if let Bson::Array(items) = value {
+                    for item in items.iter().filter_map(|i| i.as_document()) {
+                        Self::collect_fields(item, equality, range);
+                    }
+                }
+                continue;
+            }
+
+            if key.starts_with('$') {
+                continue;
+            }
+
+            let operators = match value {
+                Bson::Document(document) if document.keys().all(|k| k.starts_with('$')) => document.keys().cloned().collect(),
+                _ => vec![String::from("$eq")],
+            };
+
+            for operator in operators {
+                match operator.as_str() {
+                    "$eq" | "$in" => equality.push(key.clone()),
+                    "$not" | "$nin" | "$exists" => {},
+                    _ => range.push(key.clone()),
+                }
+            }
+        }
+    }
+
+}
+
+fn text(document: &Document, key: &str) -> String {
+    match document.get(key) {
+        Some(Bson::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+        None => String::new(),
+    }
+}
\ No newline at end of file
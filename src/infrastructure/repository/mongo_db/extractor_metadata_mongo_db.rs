@@ -3,9 +3,9 @@ use std::time::Duration;
 use chrono::Local;
 use futures_util::StreamExt;
 use mongodb::{
-    bson::{Bson, Document},
-    options::IndexVersion,
-    Collection, Cursor,
+    bson::{doc, Bson, Document},
+    options::{Collation, IndexOptions, IndexVersion},
+    Client, Collection, Cursor,
 };
 
 use crate::{
@@ -226,6 +226,208 @@ impl ExtractorMetadataMongoDb {
         Ok(group)
     }
 
+    pub(crate) async fn from_topology(client: &Client) -> Result<Vec<TableDataGroup>, ConnectException> {
+        let admin = client.database("admin");
+
+        let r_hello = admin.run_command(doc! {"hello": 1}, None).await;
+        if let Err(error) = r_hello {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        let hello = r_hello.unwrap();
+
+        let mut metadata: Vec<TableDataGroup> = Vec::new();
+        metadata.push(Self::metadata_topology(&hello));
+
+        if hello.contains_key("setName") {
+            if let Ok(status) = admin.run_command(doc! {"replSetGetStatus": 1}, None).await {
+                metadata.push(Self::metadata_replica_set(&status));
+            }
+        }
+
+        if hello.get_str("msg").unwrap_or_default() == "isdbgrid" {
+            if let Ok(shards) = admin.run_command(doc! {"listShards": 1}, None).await {
+                let balancer = admin.run_command(doc! {"balancerStatus": 1}, None).await.unwrap_or_default();
+
+                let config = client.database("config");
+                let chunks = Self::documents(config.collection::<Document>("chunks").aggregate(vec![
+                    doc! {"$group": {"_id": {"uuid": "$uuid", "ns": "$ns", "shard": "$shard"}, "chunks": {"$sum": 1}}}
+                ], None).await).await;
+                let collections = Self::documents(config.collection::<Document>("collections").aggregate(vec![
+                    doc! {"$project": {"_id": 1, "uuid": 1}}
+                ], None).await).await;
+
+                metadata.push(Self::metadata_sharding(&shards, &balancer, &chunks));
+                metadata.push(Self::metadata_chunk_balance(&chunks, &collections));
+            }
+        }
+
+        Ok(metadata)
+    }
+
+    async fn documents(r_cursor: Result<Cursor<Document>, mongodb::error::Error>) -> Vec<Document> {
+        match r_cursor {
+            Ok(cursor) => cursor.filter_map(|d| async move { d.ok() }).collect().await,
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn metadata_topology(hello: &Document) -> TableDataGroup {
+        let mut group = TableDataGroup::new(4, String::from("topology"));
+
+        let topology = if hello.get_str("msg").unwrap_or_default() == "isdbgrid" {
+            "Sharded cluster (mongos)"
+        } else if hello.contains_key("setName") {
+            "Replica set"
+        } else {
+            "Standalone"
+        };
+
+        let role = if hello.get_bool("isWritablePrimary").unwrap_or_default() {
+            "Primary"
+        } else if hello.get_bool("secondary").unwrap_or_default() {
+            "Secondary"
+        } else if hello.get_bool("arbiterOnly").unwrap_or_default() {
+            "Arbiter"
+        } else {
+            "Other"
+        };
+
+        group.push(String::from("Topology"), String::from(topology));
+        group.push(String::from("Role"), String::from(role));
+        group.push(String::from("Me"), text(hello.get("me")));
+        group.push(String::from("Set Name"), text(hello.get("setName")));
+        group.push(String::from("Primary"), text(hello.get("primary")));
+        group.push(String::from("Hosts"), list(hello.get("hosts")));
+        group.push(String::from("Passives"), list(hello.get("passives")));
+        group.push(String::from("Arbiters"), list(hello.get("arbiters")));
+        group.push(String::from("Set Version"), text(hello.get("setVersion")));
+        group.push(String::from("Max Wire Version"), text(hello.get("maxWireVersion")));
+
+        group
+    }
+
+    fn metadata_replica_set(status: &Document) -> TableDataGroup {
+        let mut group = TableDataGroup::new(5, String::from("replica_set"));
+
+        let members: Vec<&Document> = status.get_array("members")
+            .map(|m| m.iter().filter_map(|m| m.as_document()).collect())
+            .unwrap_or_default();
+
+        let primary = members.iter()
+            .find(|m| m.get_str("stateStr").unwrap_or_default() == "PRIMARY");
+
+        let primary_optime = primary
+            .and_then(|p| p.get_datetime("optimeDate").ok())
+            .map(|d| d.timestamp_millis());
+
+        let healthy = members.iter()
+            .filter(|m| m.get("health").and_then(|h| h.as_f64().or(h.as_i32().map(f64::from))).unwrap_or_default() >= 1.0)
+            .count();
+
+        group.push(String::from("Set Name"), text(status.get("set")));
+        group.push(String::from("Primary"), primary.map(|p| text(p.get("name"))).unwrap_or_default());
+        group.push(String::from("Members"), members.len().to_string());
+        group.push(String::from("Healthy Members"), healthy.to_string());
+
+        for member in members {
+            let mut details = vec![text(member.get("stateStr"))];
+
+            let health = member.get("health").and_then(|h| h.as_f64().or(h.as_i32().map(f64::from))).unwrap_or_default();
+            details.push(String::from(if health >= 1.0 { "healthy" } else { "unreachable" }));
+
+            if let (Some(primary), Ok(optime)) = (primary_optime, member.get_datetime("optimeDate")) {
+                let lag = (primary - optime.timestamp_millis()).max(0) / 1000;
+                details.push(format!("lag {}s", lag));
+            }
+
+            if let Some(ping) = member.get("pingMs") {
+                details.push(format!("ping {}ms", text(Some(ping))));
+            }
+
+            group.push(text(member.get("name")), details.join(", "));
+        }
+
+        group
+    }
+
+    fn metadata_sharding(shards: &Document, balancer: &Document, chunks: &[Document]) -> TableDataGroup {
+        let mut group = TableDataGroup::new(6, String::from("sharding"));
+
+        let shards: Vec<&Document> = shards.get_array("shards")
+            .map(|s| s.iter().filter_map(|s| s.as_document()).collect())
+            .unwrap_or_default();
+
+        group.push(String::from("Shards"), shards.len().to_string());
+        group.push(String::from("Balancer Mode"), text(balancer.get("mode")));
+        group.push(String::from("Balancer Running"), text(balancer.get("inBalancerRound")));
+
+        for shard in shards {
+            let id = text(shard.get("_id"));
+            let count: i64 = chunks.iter()
+                .filter(|c| c.get_document("_id").ok().map(|k| text(k.get("shard"))) == Some(id.clone()))
+                .map(|c| number(c.get("chunks")))
+                .sum();
+
+            let mut details = vec![text(shard.get("host"))];
+            if shard.get("state").is_some() {
+                details.push(format!("state {}", text(shard.get("state"))));
+            }
+            details.push(format!("{} chunks", count));
+
+            group.push(id, details.join(", "));
+        }
+
+        group
+    }
+
+    fn metadata_chunk_balance(chunks: &[Document], collections: &[Document]) -> TableDataGroup {
+        let mut group = TableDataGroup::new(7, String::from("chunk_balance"));
+
+        let mut balance: Vec<(String, Vec<(String, i64)>)> = Vec::new();
+        for chunk in chunks {
+            let key = match chunk.get_document("_id") {
+                Ok(key) => key,
+                Err(_) => continue,
+            };
+
+            let namespace = match key.get("ns") {
+                Some(Bson::String(ns)) => ns.clone(),
+                _ => collections.iter()
+                    .find(|c| c.get("uuid").is_some() && c.get("uuid") == key.get("uuid"))
+                    .map(|c| text(c.get("_id")))
+                    .unwrap_or(text(key.get("uuid"))),
+            };
+
+            let entry = match balance.iter().position(|(n, _)| *n == namespace) {
+                Some(position) => &mut balance[position].1,
+                None => {
+                    balance.push((namespace, Vec::new()));
+                    &mut balance.last_mut().unwrap().1
+                },
+            };
+            entry.push((text(key.get("shard")), number(chunk.get("chunks"))));
+        }
+
+        balance.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (namespace, mut shards) in balance {
+            shards.sort_by(|a, b| a.0.cmp(&b.0));
+
+            let max = shards.iter().map(|(_, c)| *c).max().unwrap_or_default();
+            let min = shards.iter().map(|(_, c)| *c).min().unwrap_or_default();
+
+            let distribution: Vec<String> = shards.iter()
+                .map(|(s, c)| format!("{}: {}", s, c))
+                .collect();
+
+            group.push(namespace, format!("{} (imbalance {})", distribution.join(", "), max - min));
+        }
+
+        group
+    }
+
     pub(crate) fn from_collection(collection_info: Document) -> Result<Vec<TableDataGroup>, ConnectException> {
         let mut metadata: Vec<TableDataGroup> = Vec::new();
         metadata.push(Self::_from_collections(vec![collection_info])?);
@@ -276,15 +478,25 @@ impl ExtractorMetadataMongoDb {
         Ok(group)
     }
 
-    pub(crate) async fn from_indexes(mut indexes: Cursor<mongodb::IndexModel>) -> Result<TableDefinition, ConnectException> {
+    pub(crate) async fn from_indexes(mut indexes: Cursor<mongodb::IndexModel>, stats: &Result<Vec<Document>, String>, collection_info: &Document) -> Result<TableDefinition, ConnectException> {
         let mut table = TableDefinition::new(String::from("Indexes"));
 
         let mut titles = TableRowDefinition::new();
         titles.push_title(String::from("Name"));
         titles.push_title(String::from("Columns"));
         titles.push_title(String::from("Version"));
-
-        let mut rows = Vec::new();
+        titles.push_title(String::from("Unique"));
+        titles.push_title(String::from("Sparse"));
+        titles.push_title(String::from("Hidden"));
+        titles.push_title(String::from("TTL (seconds)"));
+        titles.push_title(String::from("Partial filter"));
+        titles.push_title(String::from("Collation"));
+        titles.push_title(String::from("Size (bytes)"));
+        titles.push_title(String::from("Ops"));
+        titles.push_title(String::from("Since"));
+        titles.push_title(String::from("Usage"));
+
+        let mut models = Vec::new();
         while let Some(o_index) = indexes.next().await {
             if let Err(error) = o_index {
                 let exception = ConnectException::new(error.to_string());
@@ -292,40 +504,85 @@ impl ExtractorMetadataMongoDb {
             }
 
             let index = o_index.unwrap();
+            if let Some(options) = index.options {
+                models.push((index.keys, options));
+            }
+        }
+
+        let sizes = collection_info.get_document("indexSizes").cloned().unwrap_or_default();
 
+        let mut rows = Vec::new();
+        for (index_keys, options) in &models {
             let mut keys = Vec::new();
-            for key in index.keys.keys() {
-                let value = index.keys.get_i32(key);
-                if let Err(error) = value {
-                    let exception = ConnectException::new(error.to_string());
-                    return Err(exception);
+            for (key, value) in index_keys.iter() {
+                let kind = match value {
+                    Bson::String(kind) => kind.to_uppercase(),
+                    _ if key.ends_with("$**") => String::from("WILDCARD"),
+                    _ if number(Some(value)) < 0 => String::from("DSC"),
+                    _ => String::from("ASC"),
+                };
+
+                match key.as_str() {
+                    "_fts" => {
+                        let weights = options.weights.clone().unwrap_or_default();
+                        weights.keys().for_each(|k| keys.push(format!("{} - TEXT", k)));
+                    },
+                    "_ftsx" => continue,
+                    _ => keys.push(format!("{} - {}", key, kind)),
                 }
+            }
 
-                let mut direction = String::from("DSC");
-                if value.unwrap() > 0 {
-                    direction = String::from("ASC");
-                }
+            let name = options.name.clone().unwrap_or_default();
 
-                keys.push(format!("{} - {}", key, direction));
-            }
+            let usage: Vec<&Document> = stats.iter()
+                .flatten()
+                .filter(|s| s.get_str("name").unwrap_or_default() == name)
+                .filter_map(|s| s.get_document("accesses").ok())
+                .collect();
 
-            
-            if index.options.is_none() {
-                continue;
+            let ops: Option<i64> = match usage.is_empty() {
+                true => None,
+                false => Some(usage.iter().map(|a| number(a.get("ops"))).sum()),
+            };
+
+            let since = usage.iter()
+                .filter_map(|a| a.get_datetime("since").ok())
+                .min()
+                .map(|d| d.try_to_rfc3339_string().unwrap_or_default())
+                .unwrap_or_default();
+
+            let redundant = Self::index_redundant_with(&name, index_keys, options, &models);
+
+            let mut status = Vec::new();
+            if let Some(other) = &redundant {
+                status.push(format!("Redundant, prefix of {}", other));
+            }
+            match stats {
+                Err(error) => status.push(format!("Usage stats unavailable: {}", error)),
+                Ok(_) if redundant.is_none() && ops == Some(0) && name != "_id_" => status.push(String::from("Unused")),
+                Ok(_) => (),
             }
-            
-            let options = index.options.unwrap();
 
             let mut row = TableRowDefinition::new();
 
-            row.push(options.name.unwrap_or(String::new()));
+            row.push(name.clone());
             row.push(keys.join(", "));
-            row.push(index_version_to_string(options.version.unwrap_or(IndexVersion::V0)));
+            row.push(index_version_to_string(options.version.clone().unwrap_or(IndexVersion::V0)));
+            row.push(options.unique.unwrap_or_default().to_string());
+            row.push(options.sparse.unwrap_or_default().to_string());
+            row.push(options.hidden.unwrap_or_default().to_string());
+            row.push(options.expire_after.map(|e| e.as_secs().to_string()).unwrap_or_default());
+            row.push(options.partial_filter_expression.clone().map(|p| Bson::Document(p).into_relaxed_extjson().to_string()).unwrap_or_default());
+            row.push(options.collation.as_ref().map(collation_to_string).unwrap_or_default());
+            row.push(sizes.get(&name).map(|s| number(Some(s)).to_string()).unwrap_or_default());
+            row.push(ops.map(|o| o.to_string()).unwrap_or_default());
+            row.push(since);
+            row.push(status.join("; "));
 
             rows.push(row);
         }
 
-        if rows.len() > 0 {
+        if !rows.is_empty() {
             table.push(titles);
             rows.iter().for_each(|r| {
                 table.push(r.clone());
@@ -335,6 +592,24 @@ impl ExtractorMetadataMongoDb {
         Ok(table)
     }
 
+    fn index_redundant_with(name: &str, keys: &Document, options: &IndexOptions, models: &[(Document, IndexOptions)]) -> Option<String> {
+        if name == "_id_" || options.unique.unwrap_or_default() || options.sparse.unwrap_or_default()
+            || options.expire_after.is_some() || options.partial_filter_expression.is_some() {
+            return None;
+        }
+
+        let prefix: Vec<(&String, &Bson)> = keys.iter().collect();
+
+        models.iter()
+            .filter(|(_, o)| o.name.as_deref() != Some(name) && !o.hidden.unwrap_or_default())
+            .filter(|(_, o)| o.partial_filter_expression.is_none() && o.collation.as_ref().map(collation_to_string) == options.collation.as_ref().map(collation_to_string))
+            .find(|(k, _)| {
+                let other: Vec<(&String, &Bson)> = k.iter().collect();
+                other.len() > prefix.len() && other[..prefix.len()] == prefix[..]
+            })
+            .map(|(_, o)| o.name.clone().unwrap_or_default())
+    }
+
     pub(crate) async fn collection_actions(collection: Collection<Document>) -> Result<Vec<ActionDefinition>, ConnectException> {
         let json = mongo_db_collection_actions();
         let mut definition: Vec<ActionDefinition> = serde_json::from_str(&json).expect("Failed to parse JSON");
@@ -344,6 +619,60 @@ impl ExtractorMetadataMongoDb {
         Ok(definition)
     }
     
+    pub(crate) async fn collection_storage(collection: Collection<Document>) -> Result<TableDefinition, ConnectException> {
+        let o_stats = collection.aggregate(vec![doc! {"$collStats": {"storageStats": {}}}], None).await;
+        if let Err(error) = o_stats {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        let stats: Vec<Document> = o_stats.unwrap()
+            .filter_map(|d| async move { d.ok() })
+            .collect().await;
+
+        let mut table = TableDefinition::new(String::from("Storage"));
+
+        let mut titles = TableRowDefinition::new();
+        titles.push_title(String::from("Shard"));
+        titles.push_title(String::from("Documents"));
+        titles.push_title(String::from("Data size (bytes)"));
+        titles.push_title(String::from("Storage size (bytes)"));
+        titles.push_title(String::from("Reusable (bytes)"));
+        titles.push_title(String::from("Fragmentation (%)"));
+        titles.push_title(String::from("Index size (bytes)"));
+        table.push(titles);
+
+        for stat in stats {
+            let storage = stat.get_document("storageStats").cloned().unwrap_or_default();
+
+            let storage_size = number(storage.get("storageSize"));
+            let reusable = match storage.get("freeStorageSize") {
+                Some(free) => number(Some(free)),
+                None => storage.get_document("wiredTiger")
+                    .and_then(|w| w.get_document("block-manager"))
+                    .map(|b| number(b.get("file bytes available for reuse")))
+                    .unwrap_or_default(),
+            };
+
+            let fragmentation = match storage_size {
+                0 => 0.0,
+                _ => reusable as f64 * 100.0 / storage_size as f64,
+            };
+
+            let mut row = TableRowDefinition::new();
+            row.push(text(stat.get("shard")));
+            row.push(number(storage.get("count")).to_string());
+            row.push(number(storage.get("size")).to_string());
+            row.push(storage_size.to_string());
+            row.push(reusable.to_string());
+            row.push(format!("{:.1}", fragmentation));
+            row.push(number(storage.get("totalIndexSize")).to_string());
+            table.push(row);
+        }
+
+        Ok(table)
+    }
+
     async fn delete_indexes_action(collection: Collection<Document>) -> Result<ActionDefinition, ConnectException> {
         let o_indexes = collection.list_indexes(None).await;
         if let Err(error) = o_indexes {
@@ -391,6 +720,40 @@ impl ExtractorMetadataMongoDb {
     
 }
 
+fn text(value: Option<&Bson>) -> String {
+    match value {
+        Some(Bson::String(value)) => value.clone(),
+        Some(Bson::Null) | None => String::new(),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn list(value: Option<&Bson>) -> String {
+    match value {
+        Some(Bson::Array(items)) => items.iter()
+            .map(|i| text(Some(i)))
+            .collect::<Vec<String>>()
+            .join(", "),
+        _ => String::new(),
+    }
+}
+
+fn number(value: Option<&Bson>) -> i64 {
+    match value {
+        Some(Bson::Int32(value)) => *value as i64,
+        Some(Bson::Int64(value)) => *value,
+        Some(Bson::Double(value)) => *value as i64,
+        _ => 0,
+    }
+}
+
+fn collation_to_string(collation: &Collation) -> String {
+    match mongodb::bson::to_document(collation) {
+        Ok(document) => Bson::Document(document).into_relaxed_extjson().to_string(),
+        Err(_) => collation.locale.clone(),
+    }
+}
+
 pub(crate) fn index_version_to_string(version: IndexVersion) -> String {
     match version {
         IndexVersion::V0 => String::from("0"),
@@ -1,6 +1,15 @@
-use mongodb::{bson::{doc, Bson, Document}, options::IndexOptions, Collection, IndexModel};
+use std::time::Duration;
 
-use crate::{commons::exception::connect_exception::ConnectException, domain::action::generate::action::Action};
+use mongodb::{bson::{doc, to_document, Bson, Document}, options::{Collation, IndexOptions}, Collection, Database, IndexModel};
+use serde_json::Value;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        action::generate::action::Action,
+        table::definition::{table_definition::TableDefinition, table_row_definition::TableRowDefinition},
+    },
+};
 
 pub const ACTION_INDEXES_NEW: &str = "INDEXES_NEW";
 pub const FORM_FIELDS: &str = "FIELDS";
@@ -9,16 +18,37 @@ pub const FIELD_DIRECTION: &str = "DIRECTION";
 pub const FORM_ATTRIBUTES: &str = "ATTRIBUTES";
 pub const FIELD_NAME: &str = "NAME";
 pub const FIELD_UNIQUE: &str = "UNIQUE";
+pub const FIELD_SPARSE: &str = "SPARSE";
+pub const FIELD_HIDDEN: &str = "HIDDEN";
+pub const FIELD_EXPIRE_AFTER_SECONDS: &str = "EXPIRE_AFTER_SECONDS";
+pub const FIELD_PARTIAL_FILTER: &str = "PARTIAL_FILTER";
+pub const FIELD_COLLATION: &str = "COLLATION";
+
+pub const INDEX_TEXT: &str = "text";
+pub const INDEX_HASHED: &str = "hashed";
+pub const INDEX_2DSPHERE: &str = "2dsphere";
+pub const INDEX_WILDCARD: &str = "wildcard";
+const WILDCARD: &str = "$**";
 
 pub const ACTION_INDEXES_DELETE: &str = "INDEXES_DELETE";
 pub const FORM_INDEXED: &str = "INDEXED";
 pub const FIELD_INDEXED: &str = "INDEXED";
 
-pub(crate) async  fn execute_collection_action(collection: Collection<Document>, action: &Action) -> Result<String, ConnectException> {
+pub const ACTION_COLLECTION_VALIDATE: &str = "COLLECTION_VALIDATE";
+pub const FORM_VALIDATE: &str = "VALIDATE";
+pub const FIELD_FULL: &str = "FULL";
+
+pub const ACTION_COLLECTION_COMPACT: &str = "COLLECTION_COMPACT";
+pub const ACTION_COLLECTION_REINDEX: &str = "COLLECTION_REINDEX";
+
+pub(crate) async  fn execute_collection_action(data_base: &Database, collection: Collection<Document>, action: &Action) -> Result<String, ConnectException> {
     match action.action().as_str() {
         ACTION_INDEXES_NEW => create_indexes(collection, action).await,
         ACTION_INDEXES_DELETE => delete_indexes(collection, action).await,
-        _ => Err(ConnectException::new(String::from("Action not recognized.")))
+        ACTION_COLLECTION_VALIDATE => validate(data_base, collection, action).await,
+        ACTION_COLLECTION_COMPACT => compact(data_base, collection).await,
+        ACTION_COLLECTION_REINDEX => reindex(data_base, collection).await,
+        _ => Err(ConnectException::from_status(ConnectException::NOT_FOUND, String::from("Action not recognized.")))
     }
 }
 
@@ -41,7 +71,7 @@ async fn create_indexes_keys(action: &Action) -> Result<Document, ConnectExcepti
 
     let o_form_fields = action.find_form(String::from(FORM_FIELDS));
     if o_form_fields.is_none() {
-        return Err(ConnectException::new(String::from("Form data not found.")));
+        return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Form data not found.")));
     }
 
     let form_fields = o_form_fields.unwrap();
@@ -52,16 +82,27 @@ async fn create_indexes_keys(action: &Action) -> Result<Document, ConnectExcepti
             continue;
         }
 
-        let field = o_field.unwrap().value();
+        let mut field = o_field.unwrap().value();
 
-        let mut direction = 1;
-        if let Some(result) = o_direction {
-            direction = result.value()
-                .parse::<i32>()
-                .unwrap_or(1);
-        }
+        let direction = match o_direction.map(|d| d.value()).unwrap_or_default().as_str() {
+            "" => Bson::Int32(1),
+            INDEX_TEXT => Bson::String(String::from(INDEX_TEXT)),
+            INDEX_HASHED => Bson::String(String::from(INDEX_HASHED)),
+            INDEX_2DSPHERE => Bson::String(String::from(INDEX_2DSPHERE)),
+            INDEX_WILDCARD => {
+                field = match field.as_str() {
+                    "" | WILDCARD => String::from(WILDCARD),
+                    _ => format!("{}.{}", field.trim_end_matches(".$**"), WILDCARD),
+                };
+                Bson::Int32(1)
+            },
+            value => match value.parse::<i32>() {
+                Ok(direction) => Bson::Int32(direction),
+                Err(_) => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Index type '{}' not supported.", value))),
+            },
+        };
 
-        keys.insert(field, Bson::Int32(direction));
+        keys.insert(field, direction);
     }
 
     Ok(keys)
@@ -85,16 +126,72 @@ async fn create_indexes_options(action: &Action) -> Result<IndexOptions, Connect
         unique = values.value().parse::<bool>().unwrap_or(true);
     }
 
+    let sparse = attribute(form_attributes.find_fields(String::from(FIELD_SPARSE)).first().map(|f| f.value()))
+        .map(|v| v.parse::<bool>().unwrap_or_default());
+
+    let hidden = attribute(form_attributes.find_fields(String::from(FIELD_HIDDEN)).first().map(|f| f.value()))
+        .map(|v| v.parse::<bool>().unwrap_or_default());
+
+    let mut expire_after = None;
+    if let Some(value) = attribute(form_attributes.find_fields(String::from(FIELD_EXPIRE_AFTER_SECONDS)).first().map(|f| f.value())) {
+        match value.parse::<u64>() {
+            Ok(seconds) => expire_after = Some(Duration::from_secs(seconds)),
+            Err(_) => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Expire after seconds must be a positive number."))),
+        }
+    }
+
+    let mut partial_filter_expression = None;
+    if let Some(value) = attribute(form_attributes.find_fields(String::from(FIELD_PARTIAL_FILTER)).first().map(|f| f.value())) {
+        partial_filter_expression = Some(json_document(&value, "Partial filter")?);
+    }
+
+    let mut collation = None;
+    if let Some(value) = attribute(form_attributes.find_fields(String::from(FIELD_COLLATION)).first().map(|f| f.value())) {
+        collation = Some(index_collation(&value)?);
+    }
+
     Ok(IndexOptions::builder()
         .name(name)
         .unique(unique)
+        .sparse(sparse)
+        .hidden(hidden)
+        .expire_after(expire_after)
+        .partial_filter_expression(partial_filter_expression)
+        .collation(collation)
         .build())
 }
 
+fn attribute(value: Option<String>) -> Option<String> {
+    value.map(|v| String::from(v.trim())).filter(|v| !v.is_empty())
+}
+
+fn json_document(value: &str, name: &str) -> Result<Document, ConnectException> {
+    let json: Value = match serde_json::from_str(value) {
+        Ok(json) => json,
+        Err(error) => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("{} is not valid JSON: {}", name, error))),
+    };
+
+    match to_document(&json) {
+        Ok(document) => Ok(document),
+        Err(error) => Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("{} must be a JSON object: {}", name, error))),
+    }
+}
+
+pub(crate) fn index_collation(value: &str) -> Result<Collation, ConnectException> {
+    if !value.starts_with('{') {
+        return Ok(Collation::builder().locale(value).build());
+    }
+
+    match serde_json::from_str::<Collation>(value) {
+        Ok(collation) => Ok(collation),
+        Err(error) => Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Collation is not valid: {}", error))),
+    }
+}
+
 async fn delete_indexes(collection: Collection<Document>, action: &Action) -> Result<String, ConnectException> {
     let form = action.find_form(String::from(FORM_INDEXED));
     if form.is_none() {
-        return Err(ConnectException::new(String::from("Form data not found.")));
+        return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Form data not found.")));
     }
 
     let indexes = &form.unwrap().find_fields(String::from(FIELD_INDEXED));
@@ -118,4 +215,100 @@ async fn delete_indexes(collection: Collection<Document>, action: &Action) -> Re
     }
 
     Ok(String::from("No indexes removed."))
+}
+
+pub(crate) async fn validate_collection(data_base: &Database, collection: &str, sw_full: bool) -> Result<Document, ConnectException> {
+    let result = data_base.run_command(doc! {"validate": collection, "full": sw_full}, None).await;
+    if let Err(error) = result {
+        let exception = ConnectException::new(error.to_string());
+        return Err(exception);
+    }
+    Ok(result.unwrap())
+}
+
+pub(crate) fn validate_as_table(result: &Document) -> TableDefinition {
+    let mut table = TableDefinition::new(String::from("Validation"));
+
+    let mut titles = TableRowDefinition::new();
+    titles.push_title(String::from("Check"));
+    titles.push_title(String::from("Result"));
+    table.push(titles);
+
+    let mut push = |check: &str, value: String| {
+        let mut row = TableRowDefinition::new();
+        row.push(String::from(check));
+        row.push(value);
+        table.push(row);
+    };
+
+    push("Valid", result.get_bool("valid").unwrap_or_default().to_string());
+    if let Ok(repaired) = result.get_bool("repaired") {
+        push("Repaired", repaired.to_string());
+    }
+    push("Records", result.get("nrecords").map(|n| n.to_string()).unwrap_or_default());
+    push("Indexes", result.get("nIndexes").map(|n| n.to_string()).unwrap_or_default());
+    push("Invalid documents", result.get("nInvalidDocuments").map(|n| n.to_string()).unwrap_or(String::from("0")));
+    push("Corrupt records", result.get_array("corruptRecords").map(|c| c.len()).unwrap_or_default().to_string());
+
+    if let Ok(details) = result.get_document("indexDetails") {
+        for (name, detail) in details {
+            let valid = detail.as_document().and_then(|d| d.get_bool("valid").ok()).unwrap_or(true);
+            if !valid {
+                push("Invalid index", name.clone());
+            }
+        }
+    }
+
+    for (key, check) in [("errors", "Error"), ("warnings", "Warning")] {
+        for message in result.get_array(key).map(|m| m.to_vec()).unwrap_or_default() {
+            match message {
+                Bson::String(message) => push(check, message),
+                message => push(check, message.to_string()),
+            }
+        }
+    }
+
+    table
+}
+
+async fn validate(data_base: &Database, collection: Collection<Document>, action: &Action) -> Result<String, ConnectException> {
+    let sw_full = action.find_form(String::from(FORM_VALIDATE))
+        .and_then(|f| f.find_fields(String::from(FIELD_FULL)).first().map(|f| f.value()))
+        .map(|v| v.parse::<bool>().unwrap_or_default())
+        .unwrap_or_default();
+
+    let result = validate_collection(data_base, collection.name(), sw_full).await?;
+
+    let errors = result.get_array("errors").map(|e| e.len()).unwrap_or_default();
+    let warnings = result.get_array("warnings").map(|w| w.len()).unwrap_or_default();
+
+    if result.get_bool("valid").unwrap_or_default() {
+        return Ok(format!("Collection is valid, {} warning(s).", warnings));
+    }
+
+    Ok(format!("Collection is not valid: {} error(s), {} warning(s).", errors, warnings))
+}
+
+async fn compact(data_base: &Database, collection: Collection<Document>) -> Result<String, ConnectException> {
+    let result = data_base.run_command(doc! {"compact": collection.name()}, None).await;
+    if let Err(error) = result {
+        let exception = ConnectException::new(error.to_string());
+        return Err(exception);
+    }
+
+    match result.unwrap().get("bytesFreed") {
+        Some(bytes) => Ok(format!("Collection compacted, {} bytes freed.", bytes)),
+        None => Ok(String::from("Collection compacted.")),
+    }
+}
+
+async fn reindex(data_base: &Database, collection: Collection<Document>) -> Result<String, ConnectException> {
+    let result = data_base.run_command(doc! {"reIndex": collection.name()}, None).await;
+    if let Err(error) = result {
+        let message = format!("Indexes cannot be rebuilt, only standalone servers support it: {}", error);
+        return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, message));
+    }
+
+    let indexes = result.unwrap().get("nIndexesWas").map(|n| n.to_string()).unwrap_or_default();
+    Ok(format!("{} indexes rebuilt.", indexes))
 }
\ No newline at end of file
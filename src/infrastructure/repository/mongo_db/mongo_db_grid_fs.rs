@@ -0,0 +1,203 @@
+use futures_util::{io::{AsyncRead, AsyncWrite}, StreamExt};
+use mongodb::{
+    bson::{doc, oid::ObjectId, Bson, Document},
+    error::{Error, ErrorKind, GridFsErrorKind},
+    gridfs::{FilesCollectionDocument, GridFsBucket},
+    options::{GridFsBucketOptions, GridFsFindOptions, GridFsUploadOptions},
+    Database,
+};
+use serde_json::Value;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::file::{file_bucket::FileBucket, file_data::FileData, file_query::FileQuery},
+};
+
+const FILES_SUFFIX: &str = ".files";
+
+pub(crate) struct MongoDbGridFs {
+}
+
+impl MongoDbGridFs {
+
+    pub(crate) async fn buckets(data_base: &Database) -> Result<Vec<FileBucket>, ConnectException> {
+        let r_names = data_base.list_collection_names(doc! {"name": {"$regex": "\\.files$"}}).await;
+        if let Err(error) = r_names {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        let mut names = r_names.unwrap();
+        names.sort();
+
+        let mut buckets = Vec::new();
+        for name in names {
+            let pipeline = vec![doc! {"$group": {"_id": Bson::Null, "files": {"$sum": 1}, "size": {"$sum": "$length"}}}];
+
+            let r_cursor = data_base.collection::<Document>(&name).aggregate(pipeline, None).await;
+            if let Err(error) = r_cursor {
+                let exception = ConnectException::new(error.to_string());
+                return Err(exception);
+            }
+
+            let summary = match r_cursor.unwrap().next().await {
+                Some(Ok(summary)) => summary,
+                Some(Err(error)) => return Err(ConnectException::new(error.to_string())),
+                None => Document::new(),
+            };
+
+            let bucket = name.trim_end_matches(FILES_SUFFIX).to_string();
+            buckets.push(FileBucket::new(data_base.name().to_string(), bucket, number(&summary, "files"), number(&summary, "size")));
+        }
+
+        Ok(buckets)
+    }
+
+    pub(crate) async fn find_all(data_base: &Database, query: &FileQuery) -> Result<Vec<FileData>, ConnectException> {
+        let mut filter = doc! {};
+        if let Some(filename) = query.filename() {
+            filter.insert("filename", filename);
+        }
+
+        let options = GridFsFindOptions::builder()
+            .sort(doc! {"uploadDate": -1})
+            .build();
+
+        Self::find(data_base, query, filter, options).await
+    }
+
+    pub(crate) async fn upload(data_base: &Database, query: &FileQuery, filename: &str, metadata: Option<&str>, source: &mut (dyn AsyncRead + Unpin + Send)) -> Result<FileData, ConnectException> {
+        let mut document = None;
+        if let Some(metadata) = metadata {
+            let value = serde_json::from_str::<Value>(metadata).ok()
+                .and_then(|v| Bson::try_from(v).ok());
+            match value {
+                Some(Bson::Document(metadata)) => document = Some(metadata),
+                _ => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("File metadata must be a JSON object."))),
+            }
+        }
+
+        let options = GridFsUploadOptions::builder()
+            .metadata(document)
+            .build();
+
+        let result = Self::bucket(data_base, query).upload_from_futures_0_3_reader(filename, source, options).await;
+        if let Err(error) = result {
+            let exception = ConnectException::new(format!("Could not upload file: {}", error));
+            return Err(exception);
+        }
+
+        let files = Self::find(data_base, query, doc! {"_id": result.unwrap()}, None).await?;
+        match files.into_iter().next() {
+            Some(file) => Ok(file),
+            None => Err(ConnectException::new(String::from("Uploaded file not found."))),
+        }
+    }
+
+    pub(crate) async fn download(data_base: &Database, query: &FileQuery, id: &str, destination: &mut (dyn AsyncWrite + Unpin + Send)) -> Result<(), ConnectException> {
+        let result = Self::bucket(data_base, query).download_to_futures_0_3_writer(file_id(id), destination).await;
+        if let Err(error) = result {
+            return Err(file_exception(error, id));
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn delete(data_base: &Database, query: &FileQuery, id: &str) -> Result<String, ConnectException> {
+        let result = Self::bucket(data_base, query).delete(file_id(id)).await;
+        if let Err(error) = result {
+            return Err(file_exception(error, id));
+        }
+        Ok(id.to_string())
+    }
+
+    pub(crate) async fn rename(data_base: &Database, query: &FileQuery, id: &str, filename: &str) -> Result<String, ConnectException> {
+        let bucket = Self::bucket(data_base, query);
+
+        let files = Self::find(data_base, query, doc! {"_id": file_id(id)}, None).await?;
+        if files.is_empty() {
+            let exception = ConnectException::from_status(ConnectException::NOT_FOUND, format!("File '{}' not found.", id));
+            return Err(exception);
+        }
+
+        let result = bucket.rename(file_id(id), filename).await;
+        if let Err(error) = result {
+            return Err(file_exception(error, id));
+        }
+        Ok(filename.to_string())
+    }
+
+    fn bucket(data_base: &Database, query: &FileQuery) -> GridFsBucket {
+        let options = GridFsBucketOptions::builder()
+            .bucket_name(query.bucket())
+            .build();
+        data_base.gridfs_bucket(options)
+    }
+
+    async fn find(data_base: &Database, query: &FileQuery, filter: Document, options: impl Into<Option<GridFsFindOptions>>) -> Result<Vec<FileData>, ConnectException> {
+        let r_cursor = Self::bucket(data_base, query).find(filter, options).await;
+        if let Err(error) = r_cursor {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        let mut cursor = r_cursor.unwrap();
+
+        let mut files = Vec::new();
+        while let Some(r_file) = cursor.next().await {
+            if let Err(error) = r_file {
+                let exception = ConnectException::new(error.to_string());
+                return Err(exception);
+            }
+            files.push(Self::make_file_data(query, r_file.unwrap()));
+        }
+
+        Ok(files)
+    }
+
+    fn make_file_data(query: &FileQuery, file: FilesCollectionDocument) -> FileData {
+        let upload_date = file.upload_date.try_to_rfc3339_string()
+            .unwrap_or_else(|_| file.upload_date.to_string());
+        let metadata = file.metadata.map(|m| Bson::Document(m).into_relaxed_extjson().to_string());
+
+        FileData::new(
+            query, file_id_string(&file.id),
+            file.filename.unwrap_or_default(), file.length, file.chunk_size_bytes,
+            upload_date, metadata
+        )
+    }
+
+}
+
+fn file_id(id: &str) -> Bson {
+    if let Ok(oid) = ObjectId::parse_str(id) {
+        return Bson::ObjectId(oid);
+    }
+
+    serde_json::from_str::<Value>(id).ok()
+        .and_then(|v| Bson::try_from(v).ok())
+        .unwrap_or(Bson::String(id.to_string()))
+}
+
+fn file_id_string(id: &Bson) -> String {
+    match id {
+        Bson::ObjectId(oid) => oid.to_hex(),
+        Bson::String(text) => text.clone(),
+        other => other.clone().into_relaxed_extjson().to_string(),
+    }
+}
+
+fn file_exception(error: Error, id: &str) -> ConnectException {
+    if let ErrorKind::GridFs { 0: GridFsErrorKind::FileNotFound { .. }, .. } = *error.kind {
+        return ConnectException::from_status(ConnectException::NOT_FOUND, format!("File '{}' not found.", id));
+    }
+    ConnectException::new(error.to_string())
+}
+
+fn number(document: &Document, key: &str) -> u64 {
+    match document.get(key) {
+        Some(Bson::Int32(value)) => *value as u64,
+        Some(Bson::Int64(value)) => *value as u64,
+        Some(Bson::Double(value)) => *value as u64,
+        _ => 0,
+    }
+}
\ No newline at end of file
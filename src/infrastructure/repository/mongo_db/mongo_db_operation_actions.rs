@@ -0,0 +1,187 @@
+use mongodb::{
+    bson::{doc, Bson, Document},
+    error::{Error, ErrorKind},
+    Database,
+};
+
+use crate::{
+    commons::{exception::connect_exception::ConnectException, utils},
+    domain::{
+        action::{
+            definition::{
+                action_definition::ActionDefinition, action_form::ActionForm,
+                action_form_collection::ActionFormCollection, form_default::FormDefault,
+                form_field_definition::FormFieldDefinition,
+            },
+            generate::action::Action,
+        },
+        operation::{operation_data::OperationData, operation_query::OperationQuery},
+    },
+};
+
+pub const ACTION_OPERATIONS_LIST: &str = "OPERATIONS_LIST";
+pub const ACTION_OPERATIONS_KILL: &str = "OPERATIONS_KILL";
+
+pub const FORM_OPERATIONS: &str = "OPERATIONS";
+pub const FIELD_OPERATION: &str = "OPERATION";
+
+const CODE_UNAUTHORIZED: i32 = 13;
+
+pub(crate) async fn find_operations(admin: &Database, query: &OperationQuery) -> Result<Vec<OperationData>, ConnectException> {
+    let mut command = doc! {"currentOp": true, "active": true};
+    if let Some(seconds) = query.min_seconds() {
+        command.insert("secs_running", doc! {"$gte": seconds as i64});
+    }
+
+    let result = admin.run_command(command, None).await;
+    if let Err(error) = result {
+        return Err(command_exception(error));
+    }
+
+    let namespace = query.namespace().unwrap_or_default();
+
+    let operations = result.unwrap().get_array("inprog")
+        .map(|items| items.iter()
+            .filter_map(|i| i.as_document())
+            .filter(|o| !o.get_document("command").is_ok_and(|c| c.contains_key("currentOp")))
+            .filter_map(make_operation_data)
+            .filter(|o| matches_namespace(&o.namespace(), &namespace))
+            .collect())
+        .unwrap_or_default();
+
+    Ok(operations)
+}
+
+pub(crate) async fn operation_actions(admin: &Database) -> Result<Vec<ActionDefinition>, ConnectException> {
+    let operations = find_operations(admin, &OperationQuery::new()).await?;
+
+    let operation_ids: Vec<FormDefault> = operations.iter()
+        .map(|o| FormDefault::new(o.id(), format!("{} {} ({} ms)", o.operation(), o.namespace(), o.duration())))
+        .collect();
+
+    let table = utils::operations_as_table(String::from("Operations"), &operations);
+
+    let mut form = ActionForm::new(String::from(FORM_OPERATIONS), Some(String::from("Operations")), true);
+    form.push(FormFieldDefinition::new(1, String::from(FIELD_OPERATION), String::from("Operation"), true, operation_ids));
+
+    let mut forms = ActionFormCollection::new(false);
+    forms.push(form);
+
+    Ok(vec![
+        ActionDefinition::new(String::from(ACTION_OPERATIONS_LIST), String::from("Current operations"), Some(vec![table.clone()]), None),
+        ActionDefinition::new(String::from(ACTION_OPERATIONS_KILL), String::from("Kill operations"), Some(vec![table]), Some(forms)),
+    ])
+}
+
+pub(crate) async fn execute_operation_action(admin: &Database, action: &Action) -> Result<String, ConnectException> {
+    if action.action() != ACTION_OPERATIONS_KILL {
+        return Err(ConnectException::from_status(ConnectException::NOT_FOUND, String::from("Action not recognized.")));
+    }
+
+    let ids: Vec<String> = action.find_form(String::from(FORM_OPERATIONS))
+        .map(|f| f.find_fields(String::from(FIELD_OPERATION)).iter()
+            .map(|f| f.value())
+            .filter(|v| !v.is_empty())
+            .collect())
+        .unwrap_or_default();
+
+    if ids.is_empty() {
+        return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("At least one operation is required.")));
+    }
+
+    let mut killed = Vec::new();
+    let mut failed = Vec::new();
+    let mut status = None;
+    for id in &ids {
+        match admin.run_command(doc! {"killOp": 1, "op": operation_id(id)}, None).await {
+            Ok(_) => killed.push(id.clone()),
+            Err(error) => {
+                let exception = command_exception(error);
+                failed.push(format!("{} ({})", id, exception.message()));
+                status.get_or_insert(exception.status());
+            },
+        }
+    }
+
+    if killed.is_empty() {
+        let status = status.unwrap_or(ConnectException::INTERNAL_ERROR);
+        return Err(ConnectException::from_status(status, format!("No operation killed. Failed: {}.", failed.join(", "))));
+    }
+
+    let mut message = format!("{} operation(s) killed: {}.", killed.len(), killed.join(", "));
+    if !failed.is_empty() {
+        message.push_str(&format!(" {} failed: {}.", failed.len(), failed.join(", ")));
+    }
+
+    Ok(message)
+}
+
+fn matches_namespace(namespace: &str, filter: &str) -> bool {
+    if filter.is_empty() || namespace == filter {
+        return true;
+    }
+
+    // A data base alone matches every collection in it.
+    !filter.contains('.') && namespace.strip_prefix(filter).is_some_and(|rest| rest.starts_with('.'))
+}
+
+fn make_operation_data(operation: &Document) -> Option<OperationData> {
+    let id = match operation.get("opid") {
+        Some(Bson::String(id)) => id.clone(),
+        Some(Bson::Int32(id)) => id.to_string(),
+        Some(Bson::Int64(id)) => id.to_string(),
+        _ => return None,
+    };
+
+    let duration = match operation.get("microsecs_running") {
+        Some(Bson::Int64(micros)) => *micros as u64 / 1000,
+        Some(Bson::Int32(micros)) => *micros as u64 / 1000,
+        _ => match operation.get("secs_running") {
+            Some(Bson::Int64(secs)) => *secs as u64 * 1000,
+            Some(Bson::Int32(secs)) => *secs as u64 * 1000,
+            _ => 0,
+        },
+    };
+
+    let client = match operation.get_str("client") {
+        Ok(client) => String::from(client),
+        Err(_) => text(operation, "client_s"),
+    };
+
+    Some(OperationData::new(
+        id,
+        text(operation, "op"),
+        text(operation, "ns"),
+        client,
+        text(operation, "planSummary"),
+        duration,
+        text(operation, "desc"),
+    ))
+}
+
+fn operation_id(id: &str) -> Bson {
+    match id.parse::<i64>() {
+        Ok(number) => match i32::try_from(number) {
+            Ok(number) => Bson::Int32(number),
+            Err(_) => Bson::Int64(number),
+        },
+        Err(_) => Bson::String(String::from(id)),
+    }
+}
+
+fn text(document: &Document, key: &str) -> String {
+    match document.get(key) {
+        Some(Bson::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+        None => String::new(),
+    }
+}
+
+fn command_exception(error: Error) -> ConnectException {
+    let status = match *error.kind {
+        ErrorKind::Command(ref command) if command.code == CODE_UNAUTHORIZED => ConnectException::FORBIDDEN,
+        ErrorKind::Command(_) => ConnectException::BAD_REQUEST,
+        _ => ConnectException::INTERNAL_ERROR,
+    };
+    ConnectException::from_status(status, error.to_string())
+}
\ No newline at end of file
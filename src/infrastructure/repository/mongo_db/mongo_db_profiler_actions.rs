@@ -0,0 +1,292 @@
+use std::cmp::Reverse;
+
+use futures_util::StreamExt;
+use mongodb::{
+    bson::{doc, Bson, Document},
+    error::{Error, ErrorKind},
+    options::FindOptions,
+    Database,
+};
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        action::{
+            definition::{
+                action_definition::ActionDefinition, action_form::ActionForm,
+                action_form_collection::ActionFormCollection, form_default::FormDefault,
+                form_field_definition::FormFieldDefinition,
+            },
+            generate::action::Action,
+        },
+        table::{
+            definition::{table_definition::TableDefinition, table_row_definition::TableRowDefinition},
+            group::table_data_group::TableDataGroup,
+        },
+    },
+};
+
+pub const ACTION_PROFILER_STATUS: &str = "PROFILER_STATUS";
+pub const ACTION_PROFILER_SET: &str = "PROFILER_SET";
+pub const ACTION_PROFILER_REPORT: &str = "PROFILER_REPORT";
+
+pub const FORM_PROFILER: &str = "PROFILER";
+pub const FIELD_LEVEL: &str = "LEVEL";
+pub const FIELD_SLOW_MS: &str = "SLOW_MS";
+
+const PROFILE_COLLECTION: &str = "system.profile";
+const PROFILE_LIMIT: i64 = 1000;
+const SHAPE_KEYS: [&str; 6] = ["filter", "q", "query", "pipeline", "sort", "projection"];
+
+const CODE_UNAUTHORIZED: i32 = 13;
+
+struct ProfileShape {
+    namespace: String,
+    operation: String,
+    shape: String,
+    plan_summary: String,
+    count: i64,
+    total_millis: i64,
+    max_millis: i64,
+    total_examined: i64,
+    max_examined: i64,
+}
+
+pub(crate) fn is_profiler_action(code: &str) -> bool {
+    code == ACTION_PROFILER_SET
+}
+
+pub(crate) async fn profiler_actions(data_base: &Database) -> Result<Vec<ActionDefinition>, ConnectException> {
+    let status = profile_status(data_base).await?;
+    let report = profile_report(data_base).await?;
+
+    let levels = vec![
+        FormDefault::new(String::from("0"), String::from("Off")),
+        FormDefault::new(String::from("1"), String::from("Slow operations")),
+        FormDefault::new(String::from("2"), String::from("All operations")),
+    ];
+
+    let mut form = ActionForm::new(String::from(FORM_PROFILER), Some(String::from("Profiler")), false);
+    form.push(FormFieldDefinition::new(1, String::from(FIELD_LEVEL), String::from("Level"), true, levels));
+    form.push(FormFieldDefinition::new(2, String::from(FIELD_SLOW_MS), String::from("Slow threshold (ms)"), false, Vec::new()));
+
+    let mut forms = ActionFormCollection::new(false);
+    forms.push(form);
+
+    let status_table = status_as_table(&status);
+
+    Ok(vec![
+        ActionDefinition::new(String::from(ACTION_PROFILER_STATUS), String::from("Profiler status"), Some(vec![status_table.clone()]), None),
+        ActionDefinition::new(String::from(ACTION_PROFILER_SET), String::from("Set profiler"), Some(vec![status_table]), Some(forms)),
+        ActionDefinition::new(String::from(ACTION_PROFILER_REPORT), String::from("Slow queries"), Some(vec![report]), None),
+    ])
+}
+
+pub(crate) async fn execute_profiler_action(data_base: &Database, action: &Action) -> Result<String, ConnectException> {
+    if action.action() != ACTION_PROFILER_SET {
+        return Err(ConnectException::from_status(ConnectException::NOT_FOUND, String::from("Action not recognized.")));
+    }
+
+    let form = action.find_form(String::from(FORM_PROFILER));
+
+    let level = form.and_then(|f| f.find_fields(String::from(FIELD_LEVEL)).first().map(|f| f.value()))
+        .unwrap_or_default();
+    let level = match level.parse::<i32>() {
+        Ok(level) if (0..=2).contains(&level) => level,
+        _ => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Field 'LEVEL' must be 0, 1 or 2."))),
+    };
+
+    let mut command = doc! {"profile": level};
+
+    let slow_ms = form.and_then(|f| f.find_fields(String::from(FIELD_SLOW_MS)).first().map(|f| f.value()))
+        .filter(|v| !v.is_empty());
+    if let Some(slow_ms) = slow_ms {
+        match slow_ms.parse::<i32>() {
+            Ok(slow_ms) if slow_ms >= 0 => command.insert("slowms", slow_ms),
+            _ => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Field 'SLOW_MS' must be a positive number."))),
+        };
+    }
+
+    if let Err(error) = data_base.run_command(command, None).await {
+        return Err(command_exception(error));
+    }
+
+    Ok(format!("Profiler level of data base '{}' set to {}.", data_base.name(), level))
+}
+
+pub(crate) async fn profile_status(data_base: &Database) -> Result<Document, ConnectException> {
+    let result = data_base.run_command(doc! {"profile": -1}, None).await;
+    if let Err(error) = result {
+        return Err(command_exception(error));
+    }
+    Ok(result.unwrap())
+}
+
+pub(crate) fn profile_group(status: &Document) -> TableDataGroup {
+    let mut group = TableDataGroup::new(1, String::from("profiler"));
+    group.push(String::from("Level"), text(status, "was"));
+    group.push(String::from("Slow ms"), text(status, "slowms"));
+    group.push(String::from("Sample rate"), text(status, "sampleRate"));
+    group
+}
+
+pub(crate) async fn profile_report(data_base: &Database) -> Result<TableDefinition, ConnectException> {
+    let options = FindOptions::builder()
+        .sort(doc! {"ts": -1})
+        .limit(PROFILE_LIMIT)
+        .build();
+
+    let result = data_base.collection::<Document>(PROFILE_COLLECTION).find(None, options).await;
+    if let Err(error) = result {
+        return Err(command_exception(error));
+    }
+
+    let entries: Vec<Document> = result.unwrap()
+        .filter_map(|d| async move { d.ok() })
+        .collect().await;
+
+    let mut shapes: Vec<ProfileShape> = Vec::new();
+    for entry in entries {
+        let namespace = text(&entry, "ns");
+        let operation = text(&entry, "op");
+        let shape = query_shape(&entry);
+        let millis = number(&entry, "millis");
+        let examined = number(&entry, "docsExamined");
+
+        let position = shapes.iter()
+            .position(|s| s.namespace == namespace && s.operation == operation && s.shape == shape);
+
+        match position {
+            Some(position) => {
+                let current = &mut shapes[position];
+                current.count += 1;
+                current.total_millis += millis;
+                current.max_millis = current.max_millis.max(millis);
+                current.total_examined += examined;
+                current.max_examined = current.max_examined.max(examined);
+            },
+            None => shapes.push(ProfileShape {
+                namespace, operation, shape,
+                plan_summary: text(&entry, "planSummary"),
+                count: 1,
+                total_millis: millis,
+                max_millis: millis,
+                total_examined: examined,
+                max_examined: examined,
+            }),
+        }
+    }
+
+    shapes.sort_by_key(|s| Reverse(s.total_millis));
+
+    Ok(shapes_as_table(&shapes))
+}
+
+fn query_shape(entry: &Document) -> String {
+    let command = match entry.get_document("command") {
+        Ok(command) => command,
+        Err(_) => return String::new(),
+    };
+
+    let mut shape = Document::new();
+    for key in SHAPE_KEYS {
+        if let Some(value) = command.get(key) {
+            let value = match key {
+                "sort" | "projection" => value.clone(),
+                _ => normalize(value),
+            };
+            shape.insert(key, value);
+        }
+    }
+
+    Bson::Document(shape).into_relaxed_extjson().to_string()
+}
+
+fn normalize(value: &Bson) -> Bson {
+    match value {
+        Bson::Document(document) => Bson::Document(document.iter()
+            .map(|(k, v)| (k.clone(), normalize(v)))
+            .collect()),
+        Bson::Array(items) if items.iter().any(|i| matches!(i, Bson::Document(_))) => {
+            Bson::Array(items.iter().map(normalize).collect())
+        },
+        Bson::Array(_) => Bson::Array(vec![Bson::String(String::from("?"))]),
+        _ => Bson::String(String::from("?")),
+    }
+}
+
+fn status_as_table(status: &Document) -> TableDefinition {
+    let mut table = TableDefinition::new(String::from("Profiler"));
+
+    let mut titles = TableRowDefinition::new();
+    titles.push_title(String::from("Level"));
+    titles.push_title(String::from("Slow ms"));
+    titles.push_title(String::from("Sample rate"));
+    table.push(titles);
+
+    let mut row = TableRowDefinition::new();
+    row.push(text(status, "was"));
+    row.push(text(status, "slowms"));
+    row.push(text(status, "sampleRate"));
+    table.push(row);
+
+    table
+}
+
+fn shapes_as_table(shapes: &[ProfileShape]) -> TableDefinition {
+    let mut table = TableDefinition::new(String::from("Slow queries"));
+
+    let mut titles = TableRowDefinition::new();
+    titles.push_title(String::from("Namespace"));
+    titles.push_title(String::from("Operation"));
+    titles.push_title(String::from("Shape"));
+    titles.push_title(String::from("Count"));
+    titles.push_title(String::from("Avg (ms)"));
+    titles.push_title(String::from("Max (ms)"));
+    titles.push_title(String::from("Avg docs examined"));
+    titles.push_title(String::from("Max docs examined"));
+    titles.push_title(String::from("Plan summary"));
+    table.push(titles);
+
+    for shape in shapes {
+        let mut row = TableRowDefinition::new();
+        row.push(shape.namespace.clone());
+        row.push(shape.operation.clone());
+        row.push(shape.shape.clone());
+        row.push(shape.count.to_string());
+        row.push((shape.total_millis / shape.count).to_string());
+        row.push(shape.max_millis.to_string());
+        row.push((shape.total_examined / shape.count).to_string());
+        row.push(shape.max_examined.to_string());
+        row.push(shape.plan_summary.clone());
+        table.push(row);
+    }
+
+    table
+}
+
+fn text(document: &Document, key: &str) -> String {
+    match document.get(key) {
+        Some(Bson::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+        None => String::new(),
+    }
+}
+
+fn number(document: &Document, key: &str) -> i64 {
+    match document.get(key) {
+        Some(Bson::Int32(value)) => *value as i64,
+        Some(Bson::Int64(value)) => *value,
+        Some(Bson::Double(value)) => *value as i64,
+        _ => 0,
+    }
+}
+
+fn command_exception(error: Error) -> ConnectException {
+    let status = match *error.kind {
+        ErrorKind::Command(ref command) if command.code == CODE_UNAUTHORIZED => ConnectException::FORBIDDEN,
+        ErrorKind::Command(_) => ConnectException::BAD_REQUEST,
+        _ => ConnectException::INTERNAL_ERROR,
+    };
+    ConnectException::from_status(status, error.to_string())
+}
\ No newline at end of file
@@ -1,12 +1,13 @@
 use async_trait::async_trait;
 
 use mongodb::{
-    bson::{doc, to_document, Bson, Document},
-    options::{AggregateOptions, ClientOptions},
+    bson::{doc, from_bson, to_bson, to_document, Bson, Document},
+    change_stream::event::{ChangeStreamEvent, OperationType, ResumeToken},
+    options::{AggregateOptions, ChangeStreamOptions, ClientOptions, FullDocumentBeforeChangeType, FullDocumentType},
     Client, Collection, Cursor, Database,
 };
 
-use futures_util::stream::StreamExt;
+use futures_util::{io::{AsyncRead, AsyncWrite}, stream::{BoxStream, StreamExt, TryStreamExt}};
 use serde_json::{from_str, Value};
 use uuid::Uuid;
 
@@ -16,29 +17,38 @@ use crate::{
         exception::connect_exception::ConnectException,
     },
     domain::{
-        action::{definition::action_definition::ActionDefinition, generate::action::Action}, collection::{
+        aggregate::aggregate_query::AggregateQuery,
+        action::{definition::action_definition::ActionDefinition, generate::action::Action},
+        change::{change_event::ChangeEvent, e_change_operation::EChangeOperation, watch_query::WatchQuery}, collection::{
             collection_data::CollectionData, collection_definition::CollectionDefinition,
-            generate_collection_query::GenerateCollectionQuery,
+            e_collection_category::ECollectionCategory, generate_collection_query::GenerateCollectionQuery,
         }, connection_data::ConnectionData, data_base::generate_database_query::GenerateDatabaseQuery, document::{
             document_data::DocumentData, document_key::DocumentKey,
             document_key_attribute::DocumentKeyAttribute, document_schema::DocumentSchema,
-        }, e_json_type::EJSONType, field::generate::field_data::FieldData, filter::{
+        }, file::{file_bucket::FileBucket, file_data::FileData, file_query::FileQuery}, operation::{operation_data::OperationData, operation_query::OperationQuery}, e_json_type::EJSONType, field::generate::field_data::FieldData, filter::{
             collection_query::CollectionQuery, data_base_query::DataBaseQuery,
             definition::filter_definition::FilterDefinition, document_query::DocumentQuery,
             filter_element::FilterElement,
-        }, table::{definition::table_definition::TableDefinition, group::table_data_group::TableDataGroup}
+        }, query::query_explain::QueryExplain, table::{definition::table_definition::TableDefinition, group::table_data_group::TableDataGroup}
     },
-    infrastructure::repository::i_db_repository::IDBRepository,
+    infrastructure::repository::{i_db_repository::IDBRepository, i_db_transaction::IDBTransaction},
 };
 
 use super::{
     e_action::EAction, e_filter_attributes::EFilterAtributtes,
-    extractor_metadata_mongo_db::ExtractorMetadataMongoDb, mongo_db_actions::execute_collection_action,
+    extractor_explain_mongo_db::ExtractorExplainMongoDb, extractor_metadata_mongo_db::ExtractorMetadataMongoDb, mongo_db_actions::{execute_collection_action, validate_as_table, validate_collection},
+    mongo_db_grid_fs::MongoDbGridFs, mongo_db_user_actions::{data_base_actions, execute_data_base_action},
+    mongo_db_operation_actions::{execute_operation_action, find_operations, operation_actions, ACTION_OPERATIONS_KILL},
+    mongo_db_profiler_actions::{execute_profiler_action, is_profiler_action, profile_group, profile_report, profile_status, profiler_actions},
+    mongo_db_shell, mongo_db_transaction::MongoDbTransaction,
 };
 
+const ADMIN_DATA_BASE: &str = "admin";
+
 #[derive(Clone)]
 pub struct MongoDbRepository {
-    client: Client
+    client: Client,
+    read_only: bool
 }
 
 impl MongoDbRepository {
@@ -51,7 +61,8 @@ impl MongoDbRepository {
         }
         
         let instance = MongoDbRepository {
-            client: client.ok().unwrap()
+            client: client.ok().unwrap(),
+            read_only: connection.is_read_only()
         };
 
         Ok(instance)
@@ -71,17 +82,15 @@ impl MongoDbRepository {
         self.collection(&data_base, &collection)
     }
 
-    fn data_base(&self, data_base: &String) -> Database {
+    pub(crate) fn data_base(&self, data_base: &String) -> Database {
         self.client.database(&data_base)
     }
 
-    fn collection(&self, data_base: &String, collection: &String) -> Collection<Document> {
+    pub(crate) fn collection(&self, data_base: &String, collection: &String) -> Collection<Document> {
         self.data_base(data_base).collection(&collection)
     }
 
-    async fn find_cursor(&self, query: &DocumentQuery) -> Result<Cursor<Document>, ConnectException>  {
-        let collection = self.collection(&query.data_base(), &query.collection());
-
+    pub(crate) fn find_pipeline(&self, query: &DocumentQuery) -> Result<Vec<Document>, ConnectException> {
         let mut filter = FilterElement::new();
 
         let o_filter = query.filter();
@@ -91,6 +100,15 @@ impl MongoDbRepository {
 
         let mut pipeline: Vec<Document> = filter.as_mongo_agregate()?;
 
+        let sort = query.sort();
+        if !sort.is_empty() {
+            let mut order = doc! {};
+            for field in sort {
+                order.insert(field.field(), if field.is_ascending() { 1 } else { -1 });
+            }
+            pipeline.push(doc! {"$sort": order});
+        }
+
         if let Some(skip) = query.skip() {
             pipeline.push(doc! {"$skip":  Bson::Int64(skip as i64)});
         }
@@ -99,6 +117,14 @@ impl MongoDbRepository {
             pipeline.push(doc! {"$limit":  Bson::Int64(limit as i64)});
         }
 
+        Ok(pipeline)
+    }
+
+    async fn find_cursor(&self, query: &DocumentQuery) -> Result<Cursor<Document>, ConnectException>  {
+        let collection = self.collection(&query.data_base(), &query.collection());
+
+        let pipeline = self.find_pipeline(query)?;
+
         let r_cursor = collection.aggregate(pipeline, AggregateOptions::default()).await;
         if r_cursor.is_err() {
             let exception = ConnectException::new(r_cursor.unwrap_err().to_string());
@@ -208,7 +234,89 @@ impl MongoDbRepository {
         Ok(data)
     }
 
-    fn make_document_data(&self, data_base: String, collection: String, document: &Document) -> Result<DocumentData, ConnectException> {
+    fn make_aggregate_data(&self, data_base: String, collection: String, document: &Document) -> Result<DocumentData, ConnectException> {
+        let json = serde_json::to_string(document);
+        if let Err(error) = json {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        let keys = self.document_keys(document).unwrap_or_default();
+        let base_key = keys.iter().find(|k| k.name() == "_id").cloned();
+
+        Ok(DocumentData::new(
+            data_base, collection, base_key,
+            keys, json.ok().unwrap()
+        ))
+    }
+
+    fn watch_options(&self, query: &WatchQuery) -> Result<ChangeStreamOptions, ConnectException> {
+        let mut resume_after = None;
+        if let Some(token) = query.resume_token() {
+            let value = from_str::<Value>(&token).ok()
+                .and_then(|v| Bson::try_from(v).ok())
+                .and_then(|b| from_bson::<ResumeToken>(b).ok());
+            if value.is_none() {
+                let exception = ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Invalid resume token '{}'.", token));
+                return Err(exception);
+            }
+            resume_after = value;
+        }
+
+        let mut before_change = None;
+        if query.is_pre_image() {
+            before_change = Some(FullDocumentBeforeChangeType::WhenAvailable);
+        }
+
+        Ok(ChangeStreamOptions::builder()
+            .full_document(Some(FullDocumentType::UpdateLookup))
+            .full_document_before_change(before_change)
+            .resume_after(resume_after)
+            .build())
+    }
+
+    fn make_change_event(&self, query: &WatchQuery, event: ChangeStreamEvent<Document>) -> Result<Option<ChangeEvent>, ConnectException> {
+        let operation = match event.operation_type {
+            OperationType::Insert => EChangeOperation::INSERT,
+            OperationType::Update => EChangeOperation::UPDATE,
+            OperationType::Replace => EChangeOperation::REPLACE,
+            OperationType::Delete => EChangeOperation::DELETE,
+            _ => return Ok(None),
+        };
+
+        let data_base = event.ns.as_ref().map(|n| n.db.clone()).unwrap_or(query.data_base());
+        let collection = event.ns.as_ref().and_then(|n| n.coll.clone()).or(query.collection()).unwrap_or_default();
+
+        let token = to_bson(&event.id);
+        if let Err(error) = token {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        let document_key = event.document_key.as_ref().and_then(|k| serde_json::to_string(k).ok());
+        let timestamp = event.wall_time.map(|t| t.timestamp_millis() as u128)
+            .or(event.cluster_time.map(|t| t.time as u128 * 1000));
+
+        let mut change = ChangeEvent::new(operation, data_base.clone(), collection.clone(), document_key, token.unwrap().into_relaxed_extjson().to_string(), timestamp);
+
+        let pre_image = match &event.full_document_before_change {
+            Some(document) => Some(self.make_aggregate_data(data_base.clone(), collection.clone(), document)?),
+            None => None,
+        };
+        let post_image = match &event.full_document {
+            Some(document) => Some(self.make_aggregate_data(data_base, collection, document)?),
+            None => None,
+        };
+        change.set_images(pre_image, post_image);
+
+        if let Some(update) = event.update_description {
+            change.set_update(serde_json::to_string(&update.updated_fields).ok(), update.removed_fields);
+        }
+
+        Ok(Some(change))
+    }
+
+    pub(crate) fn make_document_data(&self, data_base: String, collection: String, document: &Document) -> Result<DocumentData, ConnectException> {
         let json = serde_json::to_string(&document);
         if let Err(error) = json {
             let exception = ConnectException::new(error.to_string());
@@ -242,7 +350,7 @@ impl MongoDbRepository {
 
     async fn update_document(&self, collection: &Collection<Document>, document: &Document, value: Option<&str>) -> Result<(), ConnectException> {
         if let None = value {
-            let exception = ConnectException::new(String::from("Cannot update None document."));
+            let exception = ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Cannot update None document."));
             return Err(exception);
         }
 
@@ -256,18 +364,18 @@ impl MongoDbRepository {
         Ok(())
     }
 
-    fn document_from_string(&self, value: &str) -> Result<Document, ConnectException> {
+    pub(crate) fn document_from_string(&self, value: &str) -> Result<Document, ConnectException> {
         let json: Result<Value, _> = from_str(value);
         if json.is_err() {
             let error_message = format!("Invalid JSON format: {}", json.err().unwrap());
-            let exception = ConnectException::new(error_message);
+            let exception = ConnectException::from_status(ConnectException::BAD_REQUEST, error_message);
             return Err(exception);
         }
 
         let document = to_document(&json.unwrap());
         if document.is_err() {
             let err = format!("Failed to convert JSON to BSON: {}", document.unwrap_err());
-            return Err(ConnectException::new(err));
+            return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, err));
         }
 
         Ok(document.unwrap())
@@ -278,6 +386,10 @@ impl MongoDbRepository {
 #[async_trait]
 impl IDBRepository for MongoDbRepository {
 
+    fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
     async fn status(&self) -> Result<(), ConnectException> {
         let _ = self.data_base_find_all().await?;
         return Ok(());
@@ -287,7 +399,34 @@ impl IDBRepository for MongoDbRepository {
         let server_info = &self.client.database("admin")
             .run_command(doc! {"serverStatus": 1}, None).await.unwrap();
 
-        ExtractorMetadataMongoDb::from_db(server_info)
+        let mut metadata = ExtractorMetadataMongoDb::from_db(server_info)?;
+        metadata.extend(ExtractorMetadataMongoDb::from_topology(&self.client).await?);
+
+        Ok(metadata)
+    }
+
+    async fn actions(&self) -> Result<Vec<ActionDefinition>, ConnectException> {
+        let admin = self.data_base(&String::from(ADMIN_DATA_BASE));
+        let mut actions = data_base_actions(&admin, true).await?;
+        actions.extend(operation_actions(&admin).await?);
+        Ok(actions)
+    }
+
+    async fn action(&self, code: &str) -> Result<Option<ActionDefinition>, ConnectException> {
+        let definition = self.actions().await?;
+        Ok(definition.iter().find(|d| d.action() == *code).cloned())
+    }
+
+    async fn execute_action(&self, action: &Action) -> Result<String, ConnectException> {
+        let admin = self.data_base(&String::from(ADMIN_DATA_BASE));
+        match action.action().as_str() {
+            ACTION_OPERATIONS_KILL => execute_operation_action(&admin, action).await,
+            _ => execute_data_base_action(&admin, action).await,
+        }
+    }
+
+    async fn operations(&self, query: &OperationQuery) -> Result<Vec<OperationData>, ConnectException> {
+        find_operations(&self.data_base(&String::from(ADMIN_DATA_BASE)), query).await
     }
 
     async fn data_base_find_all(&self) -> Result<Vec<String>, ConnectException> {
@@ -340,7 +479,37 @@ impl IDBRepository for MongoDbRepository {
             let document = self.collections_metadata_document(query.data_base(), collection).await?;
             documents.push(document);
         }
-        ExtractorMetadataMongoDb::from_collections(documents)
+
+        let mut metadata = ExtractorMetadataMongoDb::from_collections(documents)?;
+        if let Ok(status) = profile_status(&self.data_base(&query.data_base())).await {
+            metadata.push(profile_group(&status));
+        }
+
+        Ok(metadata)
+    }
+
+    async fn data_base_actions(&self, query: &DataBaseQuery) -> Result<Vec<ActionDefinition>, ConnectException> {
+        let data_base = self.data_base(&query.data_base());
+        let mut actions = data_base_actions(&data_base, false).await?;
+        actions.extend(profiler_actions(&data_base).await?);
+        Ok(actions)
+    }
+
+    async fn data_base_action(&self, query: &DataBaseQuery, code: &str) -> Result<Option<ActionDefinition>, ConnectException> {
+        let definition = self.data_base_actions(query).await?;
+        Ok(definition.iter().find(|d| d.action() == *code).cloned())
+    }
+
+    async fn data_base_execute_action(&self, query: &DataBaseQuery, action: &Action) -> Result<String, ConnectException> {
+        let data_base = self.data_base(&query.data_base());
+        if is_profiler_action(&action.action()) {
+            return execute_profiler_action(&data_base, action).await;
+        }
+        execute_data_base_action(&data_base, action).await
+    }
+
+    async fn data_base_profile(&self, query: &DataBaseQuery) -> Result<TableDefinition, ConnectException> {
+        profile_report(&self.data_base(&query.data_base())).await
     }
 
     async fn collection_accept_schema(&self) -> Result<CollectionDefinition, ConnectException> {        
@@ -362,7 +531,15 @@ impl IDBRepository for MongoDbRepository {
             return Err(exception);
         }
 
-        let indexes = ExtractorMetadataMongoDb::from_indexes(o_indexes.unwrap()).await?;
+        // Usage stats are optional: a missing privilege is reported in the table instead of failing it.
+        let stats: Result<Vec<Document>, String> = match collection.aggregate(vec![doc! {"$indexStats": {}}], None).await {
+            Ok(cursor) => cursor.try_collect().await.map_err(|e| e.to_string()),
+            Err(error) => Err(error.to_string()),
+        };
+
+        let collection_info = self.collections_metadata_document(query.data_base(), query.collection()).await?;
+
+        let indexes = ExtractorMetadataMongoDb::from_indexes(o_indexes.unwrap(), &stats, &collection_info).await?;
 
         let mut collection = Vec::new();
         collection.push(indexes);
@@ -386,7 +563,17 @@ impl IDBRepository for MongoDbRepository {
 
     async fn collection_execute_action(&self, query: &CollectionQuery, action: &Action) -> Result<String, ConnectException> {
         let collection = self.collection(&query.data_base(), &query.collection());
-        execute_collection_action(collection, action).await
+        execute_collection_action(&self.data_base(&query.data_base()), collection, action).await
+    }
+
+    async fn collection_validate(&self, query: &CollectionQuery, sw_full: bool) -> Result<TableDefinition, ConnectException> {
+        let result = validate_collection(&self.data_base(&query.data_base()), &query.collection(), sw_full).await?;
+        Ok(validate_as_table(&result))
+    }
+
+    async fn collection_storage(&self, query: &CollectionQuery) -> Result<TableDefinition, ConnectException> {
+        let collection = self.collection(&query.data_base(), &query.collection());
+        ExtractorMetadataMongoDb::collection_storage(collection).await
     }
 
     async fn collection_find_all(&self, query: &DataBaseQuery) -> Result<Vec<String>, ConnectException> {
@@ -408,7 +595,19 @@ impl IDBRepository for MongoDbRepository {
     async fn collection_create(&self, query: &GenerateCollectionQuery) -> Result<String, ConnectException> {
         let name = query.collection();
         let db = self.data_base(&query.data_base());
-        let result = db.create_collection(&name, None).await;
+
+        let options = query.options();
+        let fields: Vec<FieldData> = match options.category() {
+            ECollectionCategory::STANDARD | ECollectionCategory::CAPPED => query.fields(),
+            _ => query.fields().into_iter().filter(|f| f.value() != "_id").collect(),
+        };
+
+        if options.category() == ECollectionCategory::VIEW && !fields.is_empty() {
+            let exception = ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Views cannot have indexes."));
+            return Err(exception);
+        }
+
+        let result = db.create_collection(&name, options.as_mongo_create()?).await;
         if let Err(result) = result {
             let exception = ConnectException::new(result.to_string());
             return Err(exception);
@@ -416,8 +615,8 @@ impl IDBRepository for MongoDbRepository {
 
         let collection: Collection<Document> = db.collection(&name);
 
-        if query.fields().len() > 0 {
-            let indexes = FieldData::collection_as_mongo_create(query.fields())?;
+        if !fields.is_empty() {
+            let indexes = FieldData::collection_as_mongo_create(fields)?;
             if let Err(result) = collection.create_indexes(indexes, None).await {
                 let _ = self.collection_drop(query).await?;
                 let exception = ConnectException::new(result.to_string());
@@ -475,6 +674,30 @@ impl IDBRepository for MongoDbRepository {
         Ok(String::new())
     }
 
+    async fn file_buckets(&self, query: &DataBaseQuery) -> Result<Vec<FileBucket>, ConnectException> {
+        MongoDbGridFs::buckets(&self.data_base(&query.data_base())).await
+    }
+
+    async fn file_find_all(&self, query: &FileQuery) -> Result<Vec<FileData>, ConnectException> {
+        MongoDbGridFs::find_all(&self.data_base(&query.data_base()), query).await
+    }
+
+    async fn file_upload(&self, query: &FileQuery, filename: &str, metadata: Option<&str>, source: &mut (dyn AsyncRead + Unpin + Send)) -> Result<FileData, ConnectException> {
+        MongoDbGridFs::upload(&self.data_base(&query.data_base()), query, filename, metadata, source).await
+    }
+
+    async fn file_download(&self, query: &FileQuery, id: &str, destination: &mut (dyn AsyncWrite + Unpin + Send)) -> Result<(), ConnectException> {
+        MongoDbGridFs::download(&self.data_base(&query.data_base()), query, id, destination).await
+    }
+
+    async fn file_delete(&self, query: &FileQuery, id: &str) -> Result<String, ConnectException> {
+        MongoDbGridFs::delete(&self.data_base(&query.data_base()), query, id).await
+    }
+
+    async fn file_rename(&self, query: &FileQuery, id: &str, filename: &str) -> Result<String, ConnectException> {
+        MongoDbGridFs::rename(&self.data_base(&query.data_base()), query, id, filename).await
+    }
+
     async fn filter_schema(&self) -> Result<FilterDefinition, ConnectException> {        
         let json = mongo_db_filter();
 
@@ -483,15 +706,124 @@ impl IDBRepository for MongoDbRepository {
         Ok(definition)
     }
 
+    async fn parse_query(&self, data_base: &str, query: &str) -> Result<DocumentQuery, ConnectException> {
+        mongo_db_shell::parse(data_base, query)
+    }
+
     async fn find_query(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
         Ok(self.query_action(query, EAction::FIND, None).await?)
     }
 
     async fn find_all(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
-        let fix = DocumentQuery::from(query.data_base(), query.collection(), query.skip(), query.limit(), None);
+        let fix = DocumentQuery::from_sort(query.data_base(), query.collection(), query.skip(), query.limit(), None, query.sort());
         return self.find_query(&fix).await;
     }
 
+    async fn explain(&self, query: &DocumentQuery) -> Result<QueryExplain, ConnectException> {
+        let pipeline = self.find_pipeline(query)?;
+
+        let command = doc! {
+            "explain": {
+                "aggregate": query.collection(),
+                "pipeline": pipeline,
+                "cursor": {}
+            },
+            "verbosity": "executionStats"
+        };
+
+        let result = self.data_base(&query.data_base()).run_command(command, None).await;
+        if let Err(error) = result {
+            let exception = ConnectException::from_status(ConnectException::BAD_REQUEST, error.to_string());
+            return Err(exception);
+        }
+
+        Ok(ExtractorExplainMongoDb::from_explain(&result.unwrap(), query))
+    }
+
+    async fn aggregate(&self, query: &AggregateQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        let collection = self.collection(&query.data_base(), &query.collection());
+
+        let pipeline = query.pipeline().as_mongo_agregate()?;
+
+        let r_cursor = collection.aggregate(pipeline, AggregateOptions::default()).await;
+        if let Err(error) = r_cursor {
+            let exception = ConnectException::from_status(ConnectException::BAD_REQUEST, error.to_string());
+            return Err(exception);
+        }
+
+        let mut cursor = r_cursor.unwrap();
+
+        let mut documents = Vec::new();
+        while let Some(r_document) = cursor.next().await {
+            if let Err(error) = r_document {
+                let exception = ConnectException::new(error.to_string());
+                return Err(exception);
+            }
+            documents.push(self.make_aggregate_data(query.data_base(), query.collection(), &r_document.unwrap())?);
+        }
+
+        Ok(documents)
+    }
+
+    async fn watch(&self, query: &WatchQuery) -> Result<BoxStream<'static, Result<ChangeEvent, ConnectException>>, ConnectException> {
+        let options = self.watch_options(query)?;
+
+        let operations: Vec<String> = query.operations().iter()
+            .map(|o| o.to_string().to_lowercase())
+            .collect();
+        let pipeline = vec![doc! { "$match": { "operationType": { "$in": operations } } }];
+
+        let r_stream = match query.collection() {
+            Some(collection) => self.collection(&query.data_base(), &collection).watch(pipeline, options).await,
+            None => self.data_base(&query.data_base()).watch(pipeline, options).await,
+        };
+        if let Err(error) = r_stream {
+            let exception = ConnectException::from_status(ConnectException::BAD_REQUEST, error.to_string());
+            return Err(exception);
+        }
+
+        let repository = self.clone();
+        let query = query.clone();
+
+        let stream = r_stream.unwrap().filter_map(move |r_event| {
+            let result = match r_event {
+                Ok(event) => repository.make_change_event(&query, event).transpose(),
+                Err(error) => Some(Err(ConnectException::new(error.to_string()))),
+            };
+            async move { result }
+        });
+
+        Ok(stream.boxed())
+    }
+
+    async fn begin(&self) -> Result<Box<dyn IDBTransaction>, ConnectException> {
+        let r_hello = self.data_base(&String::from(ADMIN_DATA_BASE)).run_command(doc! {"hello": 1}, None).await;
+        if let Err(error) = r_hello {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        let hello = r_hello.unwrap();
+        if !hello.contains_key("setName") && hello.get_str("msg").unwrap_or_default() != "isdbgrid" {
+            let exception = ConnectException::from_status(ConnectException::NOT_IMPLEMENTED, String::from("Transactions require a replica set or a sharded cluster."));
+            return Err(exception);
+        }
+
+        let r_session = self.client.start_session(None).await;
+        if let Err(error) = r_session {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        let mut session = r_session.unwrap();
+        if let Err(error) = session.start_transaction(None).await {
+            let exception = ConnectException::from_status(ConnectException::NOT_IMPLEMENTED, error.to_string());
+            return Err(exception);
+        }
+
+        Ok(Box::new(MongoDbTransaction::new(self.clone(), session)))
+    }
+
     async fn find(&self, query: &DocumentQuery) -> Result<Option<DocumentData>, ConnectException> {
         let fix = DocumentQuery::from(query.data_base(), query.collection(), None, None, query.filter());
         let documents = self.find_query(&fix).await?.documents();
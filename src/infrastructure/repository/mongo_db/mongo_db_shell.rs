@@ -0,0 +1,591 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use mongodb::bson::{oid::ObjectId, Document};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::filter::{document_query::DocumentQuery, filter_element::FilterElement},
+};
+
+pub(crate) fn parse(data_base: &str, input: &str) -> Result<DocumentQuery, ConnectException> {
+    let mut reader = ShellReader::new(input);
+    let query = reader.query(data_base)?;
+    reader.skip_blank();
+    reader.accept(';');
+    reader.skip_blank();
+    if !reader.is_end() {
+        return Err(reader.error("Unexpected content after the query"));
+    }
+    Ok(query)
+}
+
+pub(crate) fn parse_json(input: &str) -> Result<Value, ConnectException> {
+    let mut reader = ShellReader::new(input);
+    let value = reader.value()?;
+    reader.skip_blank();
+    if !reader.is_end() {
+        return Err(reader.error("Unexpected content after the value"));
+    }
+    Ok(value)
+}
+
+struct ShellReader {
+    chars: Vec<char>,
+    position: usize
+}
+
+impl ShellReader {
+
+    fn new(input: &str) -> ShellReader {
+        ShellReader {
+            chars: input.chars().collect(),
+            position: 0
+        }
+    }
+
+    fn query(&mut self, data_base: &str) -> Result<DocumentQuery, ConnectException> {
+        self.skip_blank();
+        if self.identifier()? != "db" {
+            return Err(self.error("Query must start with 'db'"));
+        }
+
+        self.expect('.')?;
+        let mut name = self.identifier()?;
+
+        let collection = if name == "getCollection" {
+            let arguments = self.arguments()?;
+            let collection = match arguments.first() {
+                Some(Value::String(collection)) => collection.clone(),
+                _ => return Err(self.error("'getCollection' expects the collection name")),
+            };
+            self.expect('.')?;
+            name = self.identifier()?;
+            collection
+        } else {
+            self.expect('.')?;
+            let collection = name;
+            name = self.identifier()?;
+            collection
+        };
+
+        let arguments = self.arguments()?;
+
+        let mut pipeline = Vec::new();
+        let mut skip = None;
+        let mut limit = None;
+
+        match name.as_str() {
+            "find" | "findOne" => {
+                if let Some(filter) = arguments.first() {
+                    let filter = Self::as_document(filter, "filter")?;
+                    if !filter.is_empty() {
+                        pipeline.push(json!({ "$match": filter }));
+                    }
+                }
+                if let Some(projection) = arguments.get(1) {
+                    let projection = Self::as_document(projection, "projection")?;
+                    if !projection.is_empty() {
+                        pipeline.push(json!({ "$project": projection }));
+                    }
+                }
+                if name == "findOne" {
+                    limit = Some(1);
+                }
+            },
+            "aggregate" => {
+                match arguments.first() {
+                    Some(Value::Array(stages)) => pipeline.extend(stages.iter().cloned()),
+                    Some(_) => return Err(self.error("'aggregate' expects a pipeline array")),
+                    None => (),
+                }
+            },
+            _ => return Err(self.error(&format!("Method '{}' is not supported", name))),
+        }
+
+        self.skip_blank();
+        while self.accept('.') {
+            let modifier = self.identifier()?;
+            let arguments = self.arguments()?;
+            match (modifier.as_str(), arguments.first()) {
+                ("sort", Some(sort)) => {
+                    let sort = Self::as_document(sort, "sort")?;
+                    pipeline.push(json!({ "$sort": sort }));
+                },
+                ("skip", Some(value)) => skip = Some(self.as_usize(value, "skip")?),
+                ("limit", Some(value)) => limit = Some(self.as_usize(value, "limit")?),
+                _ => return Err(self.error(&format!("Modifier '{}' is not supported", modifier))),
+            }
+            self.skip_blank();
+        }
+
+        let pipeline = Value::Array(pipeline);
+        if let Err(error) = serde_json::from_value::<Vec<Document>>(pipeline.clone()) {
+            return Err(self.error(&error.to_string()));
+        }
+
+        let filter = FilterElement::query(pipeline.to_string(), Vec::new());
+
+        Ok(DocumentQuery::from(String::from(data_base), collection, skip, limit, Some(filter)))
+    }
+
+    fn arguments(&mut self) -> Result<Vec<Value>, ConnectException> {
+        self.skip_blank();
+        self.expect('(')?;
+
+        let mut arguments = Vec::new();
+        loop {
+            self.skip_blank();
+            if self.accept(')') {
+                return Ok(arguments);
+            }
+            arguments.push(self.value()?);
+            self.skip_blank();
+            if !self.accept(',') {
+                self.skip_blank();
+                self.expect(')')?;
+                return Ok(arguments);
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ConnectException> {
+        self.skip_blank();
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') | Some('\'') => Ok(Value::String(self.string()?)),
+            Some('/') => self.regex(),
+            Some(c) if c == '-' || c == '+' || c == '.' || c.is_ascii_digit() => self.number(),
+            Some(c) if Self::is_identifier(c) => self.word(),
+            Some(c) => Err(self.error(&format!("Unexpected character '{}'", c))),
+            None => Err(self.error("Unexpected end of input")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, ConnectException> {
+        self.expect('{')?;
+
+        let mut map = Map::new();
+        loop {
+            self.skip_blank();
+            if self.accept('}') {
+                return Ok(Value::Object(map));
+            }
+
+            let key = match self.peek() {
+                Some('"') | Some('\'') => self.string()?,
+                _ => self.identifier()?,
+            };
+
+            self.skip_blank();
+            self.expect(':')?;
+
+            let value = self.value()?;
+            map.insert(key, value);
+
+            self.skip_blank();
+            if !self.accept(',') {
+                self.skip_blank();
+                self.expect('}')?;
+                return Ok(Value::Object(map));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, ConnectException> {
+        self.expect('[')?;
+
+        let mut items = Vec::new();
+        loop {
+            self.skip_blank();
+            if self.accept(']') {
+                return Ok(Value::Array(items));
+            }
+
+            items.push(self.value()?);
+
+            self.skip_blank();
+            if !self.accept(',') {
+                self.skip_blank();
+                self.expect(']')?;
+                return Ok(Value::Array(items));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ConnectException> {
+        let quote = match self.next() {
+            Some(quote) => quote,
+            None => return Err(self.error("Expected a string")),
+        };
+
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                Some(c) if c == quote => return Ok(value),
+                Some('\\') => {
+                    let escaped = match self.next() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('u') => self.unicode()?,
+                        Some(c) => c,
+                        None => return Err(self.error("Unterminated string")),
+                    };
+                    value.push(escaped);
+                },
+                Some(c) => value.push(c),
+                None => return Err(self.error("Unterminated string")),
+            }
+        }
+    }
+
+    fn unicode(&mut self) -> Result<char, ConnectException> {
+        let mut code = String::new();
+        for _ in 0..4 {
+            match self.next() {
+                Some(c) => code.push(c),
+                None => return Err(self.error("Invalid unicode escape")),
+            }
+        }
+        u32::from_str_radix(&code, 16).ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error("Invalid unicode escape"))
+    }
+
+    fn regex(&mut self) -> Result<Value, ConnectException> {
+        self.expect('/')?;
+
+        let mut pattern = String::new();
+        loop {
+            match self.next() {
+                Some('/') => break,
+                Some('\\') => {
+                    pattern.push('\\');
+                    match self.next() {
+                        Some(c) => pattern.push(c),
+                        None => return Err(self.error("Unterminated regular expression")),
+                    }
+                },
+                Some(c) => pattern.push(c),
+                None => return Err(self.error("Unterminated regular expression")),
+            }
+        }
+
+        let mut options = String::new();
+        while let Some(c) = self.peek().filter(|c| c.is_ascii_alphabetic()) {
+            options.push(c);
+            self.position += 1;
+        }
+
+        Ok(json!({ "$regularExpression": { "pattern": pattern, "options": options } }))
+    }
+
+    fn number(&mut self) -> Result<Value, ConnectException> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.')) {
+            self.position += 1;
+        }
+
+        let text: String = self.chars[start..self.position].iter().collect();
+        let text = text.trim_start_matches('+');
+
+        if let Ok(integer) = text.parse::<i64>() {
+            return Ok(Value::from(integer));
+        }
+
+        match text.parse::<f64>() {
+            Ok(float) if float.is_finite() => Ok(Value::from(float)),
+            _ => Err(self.error(&format!("Invalid number '{}'", text))),
+        }
+    }
+
+    fn word(&mut self) -> Result<Value, ConnectException> {
+        let mut word = self.identifier()?;
+
+        if word == "new" {
+            self.skip_blank();
+            word = self.identifier()?;
+        }
+
+        match word.as_str() {
+            "true" => return Ok(Value::Bool(true)),
+            "false" => return Ok(Value::Bool(false)),
+            "null" | "undefined" => return Ok(Value::Null),
+            _ => (),
+        }
+
+        let arguments = self.arguments()?;
+        let argument = arguments.first();
+
+        match word.as_str() {
+            "ObjectId" => {
+                let oid = match argument {
+                    Some(Value::String(hex)) => ObjectId::parse_str(hex)
+                        .map_err(|_| self.error(&format!("Invalid ObjectId '{}'", hex)))?,
+                    None => ObjectId::new(),
+                    _ => return Err(self.error("'ObjectId' expects a hexadecimal string")),
+                };
+                Ok(json!({ "$oid": oid.to_hex() }))
+            },
+            "ISODate" | "Date" => {
+                let millis = match argument {
+                    Some(Value::String(date)) => self.date(date)?,
+                    Some(Value::Number(millis)) => millis.as_i64()
+                        .ok_or_else(|| self.error("Date milliseconds must be an integer"))?,
+                    None => Utc::now().timestamp_millis(),
+                    _ => return Err(self.error("Dates expect an ISO-8601 string")),
+                };
+                Ok(json!({ "$date": { "$numberLong": millis.to_string() } }))
+            },
+            "NumberLong" => Ok(json!({ "$numberLong": self.integer(argument, &word)?.to_string() })),
+            "NumberInt" => {
+                let integer = self.integer(argument, &word)?;
+                if i32::try_from(integer).is_err() {
+                    return Err(self.error(&format!("Value {} is out of range for 'NumberInt'", integer)));
+                }
+                Ok(json!({ "$numberInt": integer.to_string() }))
+            },
+            "NumberDecimal" => match argument {
+                Some(Value::String(decimal)) => Ok(json!({ "$numberDecimal": decimal })),
+                Some(Value::Number(decimal)) => Ok(json!({ "$numberDecimal": decimal.to_string() })),
+                _ => Err(self.error("'NumberDecimal' expects a number")),
+            },
+            _ => Err(self.error(&format!("Unknown constructor '{}'", word))),
+        }
+    }
+
+    fn integer(&self, argument: Option<&Value>, constructor: &str) -> Result<i64, ConnectException> {
+        let integer = match argument {
+            Some(Value::Number(number)) => number.as_i64(),
+            Some(Value::String(text)) => text.parse::<i64>().ok(),
+            _ => None,
+        };
+        integer.ok_or_else(|| self.error(&format!("'{}' expects an integer", constructor)))
+    }
+
+    fn date(&self, date: &str) -> Result<i64, ConnectException> {
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(date) {
+            return Ok(date_time.timestamp_millis());
+        }
+
+        for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M"] {
+            if let Ok(date_time) = NaiveDateTime::parse_from_str(date, format) {
+                return Ok(date_time.and_utc().timestamp_millis());
+            }
+        }
+
+        match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+            Ok(date) => Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc().timestamp_millis()),
+            Err(_) => Err(self.error(&format!("Invalid date '{}'", date))),
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, ConnectException> {
+        self.skip_blank();
+        let start = self.position;
+        while self.peek().is_some_and(|c| Self::is_identifier(c) || c.is_ascii_digit()) {
+            self.position += 1;
+        }
+
+        if start == self.position {
+            return Err(self.error("Expected an identifier"));
+        }
+
+        Ok(self.chars[start..self.position].iter().collect())
+    }
+
+    fn is_identifier(c: char) -> bool {
+        c.is_alphabetic() || c == '_' || c == '$'
+    }
+
+    fn as_document(value: &Value, name: &str) -> Result<Map<String, Value>, ConnectException> {
+        match value {
+            Value::Object(document) => Ok(document.clone()),
+            _ => Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("The {} must be a document.", name))),
+        }
+    }
+
+    fn as_usize(&self, value: &Value, name: &str) -> Result<usize, ConnectException> {
+        value.as_u64()
+            .and_then(|v| usize::try_from(v).ok())
+            .ok_or_else(|| self.error(&format!("'{}' expects a positive integer", name)))
+    }
+
+    fn skip_blank(&mut self) {
+        loop {
+            while self.peek().is_some_and(|c| c.is_whitespace()) {
+                self.position += 1;
+            }
+
+            if self.starts_with("//") {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.position += 1;
+                }
+            } else if self.starts_with("/*") {
+                self.position += 2;
+                while !self.is_end() && !self.starts_with("*/") {
+                    self.position += 1;
+                }
+                self.position = (self.position + 2).min(self.chars.len());
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn starts_with(&self, token: &str) -> bool {
+        token.chars().enumerate().all(|(i, c)| self.chars.get(self.position + i) == Some(&c))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.position += 1;
+        }
+        c
+    }
+
+    fn accept(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ConnectException> {
+        self.skip_blank();
+        if self.accept(expected) {
+            return Ok(());
+        }
+        Err(self.error(&format!("Expected '{}'", expected)))
+    }
+
+    fn is_end(&self) -> bool {
+        self.position >= self.chars.len()
+    }
+
+    fn error(&self, message: &str) -> ConnectException {
+        ConnectException::from_status(ConnectException::BAD_REQUEST, format!("{} at position {}.", message, self.position))
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn pipeline(input: &str) -> Value {
+        let query = parse("app", input).unwrap();
+        serde_json::from_str(&query.filter().unwrap().value().value()).unwrap()
+    }
+
+    fn assert_bad_request(result: Result<Value, ConnectException>) {
+        match result {
+            Ok(value) => panic!("Expected an error, found {}", value),
+            Err(error) => assert_eq!(error.status(), ConnectException::BAD_REQUEST),
+        }
+    }
+
+    #[test]
+    fn parses_find_with_projection() {
+        let query = parse("app", "db.users.find({age: {$gt: 30}}, {name: 1});").unwrap();
+        assert_eq!(query.data_base(), "app");
+        assert_eq!(query.collection(), "users");
+        assert_eq!(pipeline("db.users.find({age: {$gt: 30}}, {name: 1})"), json!([
+            {"$match": {"age": {"$gt": 30}}},
+            {"$project": {"name": 1}}
+        ]));
+    }
+
+    #[test]
+    fn parses_get_collection_and_find_one() {
+        let query = parse("app", "db.getCollection('audit-log').findOne({})").unwrap();
+        assert_eq!(query.collection(), "audit-log");
+        assert_eq!(query.limit(), Some(1));
+        assert_eq!(pipeline("db.getCollection('audit-log').findOne({})"), json!([]));
+    }
+
+    #[test]
+    fn parses_chained_modifiers() {
+        let query = parse("app", "db.users.find().sort({name: -1}).skip(20).limit(10)").unwrap();
+        assert_eq!(query.skip(), Some(20));
+        assert_eq!(query.limit(), Some(10));
+        assert_eq!(pipeline("db.users.find().sort({name: -1}).skip(20).limit(10)"), json!([{"$sort": {"name": -1}}]));
+
+        assert!(parse("app", "db.users.find().limit(-1)").is_err());
+        assert!(parse("app", "db.users.find().count()").is_err());
+    }
+
+    #[test]
+    fn parses_aggregate() {
+        assert_eq!(pipeline("db.users.aggregate([{$group: {_id: '$city', total: {$sum: 1}}}])"), json!([
+            {"$group": {"_id": "$city", "total": {"$sum": 1}}}
+        ]));
+        assert!(parse("app", "db.users.aggregate({})").is_err());
+    }
+
+    #[test]
+    fn parses_constructors() {
+        let value = parse_json("{_id: ObjectId('65a1b2c3d4e5f60718293a4b'), at: ISODate('2024-01-02T03:04:05Z'), day: new Date('2024-01-02'), count: NumberLong(42), small: NumberInt('7'), price: NumberDecimal('9.99')}").unwrap();
+        assert_eq!(value, json!({
+            "_id": {"$oid": "65a1b2c3d4e5f60718293a4b"},
+            "at": {"$date": {"$numberLong": "1704164645000"}},
+            "day": {"$date": {"$numberLong": "1704153600000"}},
+            "count": {"$numberLong": "42"},
+            "small": {"$numberInt": "7"},
+            "price": {"$numberDecimal": "9.99"}
+        }));
+
+        assert_bad_request(parse_json("ObjectId('nothex')"));
+        assert_bad_request(parse_json("ISODate('yesterday')"));
+        assert_bad_request(parse_json("NumberInt(3000000000)"));
+        assert_bad_request(parse_json("NumberLong('1.5')"));
+        assert_bad_request(parse_json("Timestamp(1, 2)"));
+    }
+
+    #[test]
+    fn parses_regex_literals() {
+        assert_eq!(parse_json(r"/^a\/b/i").unwrap(), json!({"$regularExpression": {"pattern": r"^a\/b", "options": "i"}}));
+        assert_eq!(pipeline("db.users.find({name: /smith$/})"), json!([
+            {"$match": {"name": {"$regularExpression": {"pattern": "smith$", "options": ""}}}}
+        ]));
+        assert_bad_request(parse_json("/unterminated"));
+    }
+
+    #[test]
+    fn parses_relaxed_json() {
+        let value = parse_json("{'single': 'it\\'s', \"double\": \"\\u00e9\", bare: [1, -2.5, +3, true, null, undefined,],}").unwrap();
+        assert_eq!(value, json!({"single": "it's", "double": "é", "bare": [1, -2.5, 3, true, null, null]}));
+    }
+
+    #[test]
+    fn skips_comments() {
+        let input = "// recent users\ndb.users.find({ /* adults */ age: {$gte: 18} }) // done";
+        assert_eq!(pipeline(input), json!([{"$match": {"age": {"$gte": 18}}}]));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for input in ["", "users.find()", "db.users", "db.users.find(", "db.users.find({a: 1)", "db.users.find({a: 'x})", "db.users.remove({})", "db.users.find() extra", "db.users.find(5)"] {
+            match parse("app", input) {
+                Ok(_) => panic!("Expected an error for '{}'", input),
+                Err(error) => assert_eq!(error.status(), ConnectException::BAD_REQUEST, "{}", input),
+            }
+        }
+
+        assert_bad_request(parse_json("{a: 1} {b: 2}"));
+        assert_bad_request(parse_json("{a: 1e999}"));
+        assert_bad_request(parse_json("'\\uZZZZ'"));
+        assert_bad_request(parse_json("{a: #}"));
+    }
+
+}
\ No newline at end of file
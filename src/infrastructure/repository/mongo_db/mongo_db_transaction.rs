@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+
+use mongodb::{
+    bson::{doc, Document},
+    ClientSession,
+};
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        document::document_data::DocumentData,
+        filter::{collection_query::CollectionQuery, document_query::DocumentQuery},
+    },
+    infrastructure::repository::i_db_transaction::IDBTransaction,
+};
+
+use super::mongo_db_repository::MongoDbRepository;
+
+pub struct MongoDbTransaction {
+    repository: MongoDbRepository,
+    session: ClientSession
+}
+
+impl MongoDbTransaction {
+
+    pub(crate) fn new(repository: MongoDbRepository, session: ClientSession) -> Self {
+        Self {
+            repository, session
+        }
+    }
+
+    async fn find_documents(&mut self, query: &DocumentQuery) -> Result<Vec<Document>, ConnectException> {
+        let collection = self.repository.collection(&query.data_base(), &query.collection());
+
+        let pipeline = self.repository.find_pipeline(query)?;
+
+        let r_cursor = collection.aggregate_with_session(pipeline, None, &mut self.session).await;
+        if let Err(error) = r_cursor {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        let mut cursor = r_cursor.unwrap();
+
+        let mut documents = Vec::new();
+        while let Some(r_document) = cursor.next(&mut self.session).await {
+            if let Err(error) = r_document {
+                let exception = ConnectException::new(error.to_string());
+                return Err(exception);
+            }
+            documents.push(r_document.unwrap());
+        }
+
+        Ok(documents)
+    }
+
+    fn make_documents_data(&self, query: &DocumentQuery, documents: &[Document]) -> Result<Vec<DocumentData>, ConnectException> {
+        documents.iter()
+            .map(|d| self.repository.make_document_data(query.data_base(), query.collection(), d))
+            .collect()
+    }
+
+}
+
+#[async_trait]
+impl IDBTransaction for MongoDbTransaction {
+
+    async fn insert(&mut self, query: &CollectionQuery, value: &str) -> Result<DocumentData, ConnectException> {
+        let collection = self.repository.collection(&query.data_base(), &query.collection());
+
+        let mut document = self.repository.document_from_string(value)?;
+
+        let result = collection.insert_one_with_session(document.clone(), None, &mut self.session).await;
+        if let Err(error) = result {
+            let exception = ConnectException::new(format!("Could not insert into database: {}", error));
+            return Err(exception);
+        }
+
+        document.insert("_id", result.unwrap().inserted_id);
+
+        self.repository.make_document_data(query.data_base(), query.collection(), &document)
+    }
+
+    async fn update(&mut self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        let collection = self.repository.collection(&query.data_base(), &query.collection());
+
+        let new_document = self.repository.document_from_string(value)?;
+
+        let documents = self.find_documents(query).await?;
+        for document in &documents {
+            let result = collection.replace_one_with_session(document.clone(), new_document.clone(), None, &mut self.session).await;
+            if let Err(error) = result {
+                let exception = ConnectException::new(error.to_string());
+                return Err(exception);
+            }
+        }
+
+        self.make_documents_data(query, &documents)
+    }
+
+    async fn delete(&mut self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        let collection = self.repository.collection(&query.data_base(), &query.collection());
+
+        let documents = self.find_documents(query).await?;
+
+        let ids: Vec<_> = documents.iter()
+            .filter_map(|d| d.get("_id").cloned())
+            .collect();
+
+        let result = collection.delete_many_with_session(doc! { "_id": { "$in": ids } }, None, &mut self.session).await;
+        if let Err(error) = result {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        self.make_documents_data(query, &documents)
+    }
+
+    async fn collection_import(&mut self, query: &CollectionQuery, documents: Vec<String>) -> Result<String, ConnectException> {
+        let collection = self.repository.collection(&query.data_base(), &query.collection());
+
+        let mut parsed = Vec::new();
+        for document in documents {
+            parsed.push(self.repository.document_from_string(&document)?);
+        }
+
+        if let Err(error) = collection.insert_many_with_session(parsed, None, &mut self.session).await {
+            let exception = ConnectException::new(error.to_string());
+            return Err(exception);
+        }
+
+        Ok(String::new())
+    }
+
+    async fn commit(&mut self) -> Result<(), ConnectException> {
+        if let Err(error) = self.session.commit_transaction().await {
+            let exception = ConnectException::from_status(ConnectException::CONFLICT, format!("Transaction could not be committed: {}", error));
+            return Err(exception);
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<(), ConnectException> {
+        if let Err(error) = self.session.abort_transaction().await {
+            let exception = ConnectException::new(format!("Transaction could not be aborted: {}", error));
+            return Err(exception);
+        }
+        Ok(())
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,281 @@
+use mongodb::{
+    bson::{doc, Bson, Document},
+    error::{Error, ErrorKind},
+    Database,
+};
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        action::{
+            definition::{
+                action_definition::ActionDefinition, action_form::ActionForm,
+                action_form_collection::ActionFormCollection, form_default::FormDefault,
+                form_field_definition::FormFieldDefinition,
+            },
+            generate::action::Action,
+        },
+        table::definition::{table_definition::TableDefinition, table_row_definition::TableRowDefinition},
+    },
+};
+
+pub const ACTION_USERS_LIST: &str = "USERS_LIST";
+pub const ACTION_ROLES_LIST: &str = "ROLES_LIST";
+pub const ACTION_USERS_NEW: &str = "USERS_NEW";
+pub const ACTION_USERS_DROP: &str = "USERS_DROP";
+pub const ACTION_USERS_PASSWORD: &str = "USERS_PASSWORD";
+pub const ACTION_ROLES_GRANT: &str = "ROLES_GRANT";
+pub const ACTION_ROLES_REVOKE: &str = "ROLES_REVOKE";
+
+pub const FORM_USER: &str = "USER";
+pub const FIELD_USER: &str = "USER";
+pub const FIELD_PASSWORD: &str = "PASSWORD";
+pub const FORM_ROLES: &str = "ROLES";
+pub const FIELD_ROLE: &str = "ROLE";
+pub const FIELD_DATA_BASE: &str = "DATA_BASE";
+
+const CODE_UNAUTHORIZED: i32 = 13;
+const CODE_USER_NOT_FOUND: i32 = 11;
+const CODE_DUPLICATE_KEY: i32 = 51003;
+
+pub(crate) async fn data_base_actions(data_base: &Database, sw_all: bool) -> Result<Vec<ActionDefinition>, ConnectException> {
+    let users = find_users(data_base, sw_all).await?;
+    let roles = find_roles(data_base).await?;
+
+    let user_names: Vec<FormDefault> = users.iter()
+        .filter(|u| text(u, "db") == data_base.name())
+        .map(|u| FormDefault::new(text(u, "user"), text(u, "user")))
+        .collect();
+
+    let role_names: Vec<FormDefault> = roles.iter()
+        .map(|r| FormDefault::new(text(r, "role"), text(r, "role")))
+        .collect();
+
+    let users_table = users_as_table(&users);
+
+    Ok(vec![
+        ActionDefinition::new(String::from(ACTION_USERS_LIST), String::from("Users"), Some(vec![users_table.clone()]), None),
+        ActionDefinition::new(String::from(ACTION_ROLES_LIST), String::from("Roles"), Some(vec![roles_as_table(&roles)]), None),
+        ActionDefinition::new(String::from(ACTION_USERS_NEW), String::from("New user"), None, Some(forms(vec![
+            user_form(Vec::new(), true),
+            roles_form(role_names.clone())
+        ]))),
+        ActionDefinition::new(String::from(ACTION_USERS_DROP), String::from("Drop user"), Some(vec![users_table.clone()]), Some(forms(vec![
+            user_form(user_names.clone(), false)
+        ]))),
+        ActionDefinition::new(String::from(ACTION_USERS_PASSWORD), String::from("Change password"), None, Some(forms(vec![
+            user_form(user_names.clone(), true)
+        ]))),
+        ActionDefinition::new(String::from(ACTION_ROLES_GRANT), String::from("Grant roles"), Some(vec![users_table.clone()]), Some(forms(vec![
+            user_form(user_names.clone(), false),
+            roles_form(role_names.clone())
+        ]))),
+        ActionDefinition::new(String::from(ACTION_ROLES_REVOKE), String::from("Revoke roles"), Some(vec![users_table]), Some(forms(vec![
+            user_form(user_names, false),
+            roles_form(role_names)
+        ]))),
+    ])
+}
+
+pub(crate) async fn execute_data_base_action(data_base: &Database, action: &Action) -> Result<String, ConnectException> {
+    let (command, message) = match action.action().as_str() {
+        ACTION_USERS_NEW => {
+            let user = require_field(action, FORM_USER, FIELD_USER)?;
+            let password = require_field(action, FORM_USER, FIELD_PASSWORD)?;
+            let command = doc! {"createUser": &user, "pwd": password, "roles": roles(data_base, action)};
+            (command, format!("User '{}' created.", user))
+        },
+        ACTION_USERS_DROP => {
+            let user = require_field(action, FORM_USER, FIELD_USER)?;
+            (doc! {"dropUser": &user}, format!("User '{}' dropped.", user))
+        },
+        ACTION_USERS_PASSWORD => {
+            let user = require_field(action, FORM_USER, FIELD_USER)?;
+            let password = require_field(action, FORM_USER, FIELD_PASSWORD)?;
+            (doc! {"updateUser": &user, "pwd": password}, format!("Password of user '{}' changed.", user))
+        },
+        ACTION_ROLES_GRANT | ACTION_ROLES_REVOKE => {
+            let user = require_field(action, FORM_USER, FIELD_USER)?;
+            let roles = roles(data_base, action);
+            if roles.is_empty() {
+                return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("At least one role is required.")));
+            }
+
+            if action.action() == ACTION_ROLES_GRANT {
+                (doc! {"grantRolesToUser": &user, "roles": roles}, format!("Roles granted to user '{}'.", user))
+            } else {
+                (doc! {"revokeRolesFromUser": &user, "roles": roles}, format!("Roles revoked from user '{}'.", user))
+            }
+        },
+        _ => return Err(ConnectException::from_status(ConnectException::NOT_FOUND, String::from("Action not recognized."))),
+    };
+
+    if let Err(error) = data_base.run_command(command, None).await {
+        return Err(command_exception(error));
+    }
+
+    Ok(message)
+}
+
+async fn find_users(data_base: &Database, sw_all: bool) -> Result<Vec<Document>, ConnectException> {
+    let command = match sw_all {
+        true => doc! {"usersInfo": {"forAllDBs": true}},
+        false => doc! {"usersInfo": 1},
+    };
+
+    let result = data_base.run_command(command, None).await;
+    if let Err(error) = result {
+        return Err(command_exception(error));
+    }
+
+    Ok(documents(&result.unwrap(), "users"))
+}
+
+async fn find_roles(data_base: &Database) -> Result<Vec<Document>, ConnectException> {
+    let result = data_base.run_command(doc! {"rolesInfo": 1, "showBuiltinRoles": true}, None).await;
+    if let Err(error) = result {
+        return Err(command_exception(error));
+    }
+
+    Ok(documents(&result.unwrap(), "roles"))
+}
+
+fn users_as_table(users: &[Document]) -> TableDefinition {
+    let mut table = TableDefinition::new(String::from("Users"));
+
+    let mut titles = TableRowDefinition::new();
+    titles.push_title(String::from("User"));
+    titles.push_title(String::from("Data base"));
+    titles.push_title(String::from("Roles"));
+    titles.push_title(String::from("Mechanisms"));
+    table.push(titles);
+
+    for user in users {
+        let mechanisms: Vec<String> = user.get_array("mechanisms").map(|m| m.iter()
+            .filter_map(|m| m.as_str().map(String::from))
+            .collect())
+            .unwrap_or_default();
+
+        let mut row = TableRowDefinition::new();
+        row.push(text(user, "user"));
+        row.push(text(user, "db"));
+        row.push(role_names(user, "roles"));
+        row.push(mechanisms.join(", "));
+        table.push(row);
+    }
+
+    table
+}
+
+fn roles_as_table(roles: &[Document]) -> TableDefinition {
+    let mut table = TableDefinition::new(String::from("Roles"));
+
+    let mut titles = TableRowDefinition::new();
+    titles.push_title(String::from("Role"));
+    titles.push_title(String::from("Data base"));
+    titles.push_title(String::from("Built-in"));
+    titles.push_title(String::from("Inherited roles"));
+    table.push(titles);
+
+    for role in roles {
+        let mut row = TableRowDefinition::new();
+        row.push(text(role, "role"));
+        row.push(text(role, "db"));
+        row.push(text(role, "isBuiltin"));
+        row.push(role_names(role, "roles"));
+        table.push(row);
+    }
+
+    table
+}
+
+fn forms(forms: Vec<ActionForm>) -> ActionFormCollection {
+    let mut collection = ActionFormCollection::new(false);
+    for form in forms {
+        collection.push(form);
+    }
+    collection
+}
+
+fn user_form(users: Vec<FormDefault>, sw_password: bool) -> ActionForm {
+    let mut form = ActionForm::new(String::from(FORM_USER), Some(String::from("User")), false);
+    form.push(FormFieldDefinition::new(1, String::from(FIELD_USER), String::from("User"), true, users));
+    if sw_password {
+        form.push(FormFieldDefinition::new(2, String::from(FIELD_PASSWORD), String::from("Password"), false, Vec::new()));
+    }
+    form
+}
+
+fn roles_form(roles: Vec<FormDefault>) -> ActionForm {
+    let mut form = ActionForm::new(String::from(FORM_ROLES), Some(String::from("Roles")), true);
+    form.push(FormFieldDefinition::new(1, String::from(FIELD_ROLE), String::from("Role"), true, roles));
+    form.push(FormFieldDefinition::new(2, String::from(FIELD_DATA_BASE), String::from("Data base"), false, Vec::new()));
+    form
+}
+
+fn require_field(action: &Action, form: &str, field: &str) -> Result<String, ConnectException> {
+    let value = action.find_form(String::from(form))
+        .and_then(|f| f.find_fields(String::from(field)).first().map(|f| f.value()))
+        .unwrap_or_default();
+
+    if value.is_empty() {
+        let exception = ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Field '{}' is required.", field));
+        return Err(exception);
+    }
+
+    Ok(value)
+}
+
+fn roles(data_base: &Database, action: &Action) -> Vec<Document> {
+    let form = match action.find_form(String::from(FORM_ROLES)) {
+        Some(form) => form,
+        None => return Vec::new(),
+    };
+
+    form.fields().iter()
+        .filter_map(|fields| {
+            let role = fields.iter().find(|f| f.code() == FIELD_ROLE).map(|f| f.value()).unwrap_or_default();
+            if role.is_empty() {
+                return None;
+            }
+
+            let db = fields.iter().find(|f| f.code() == FIELD_DATA_BASE).map(|f| f.value())
+                .filter(|d| !d.is_empty())
+                .unwrap_or(data_base.name().to_string());
+
+            Some(doc! {"role": role, "db": db})
+        })
+        .collect()
+}
+
+fn documents(document: &Document, key: &str) -> Vec<Document> {
+    document.get_array(key)
+        .map(|items| items.iter().filter_map(|i| i.as_document().cloned()).collect())
+        .unwrap_or_default()
+}
+
+fn role_names(document: &Document, key: &str) -> String {
+    documents(document, key).iter()
+        .map(|r| format!("{}@{}", text(r, "role"), text(r, "db")))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn text(document: &Document, key: &str) -> String {
+    match document.get(key) {
+        Some(Bson::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+        None => String::new(),
+    }
+}
+
+fn command_exception(error: Error) -> ConnectException {
+    let status = match *error.kind {
+        ErrorKind::Command(ref command) if command.code == CODE_UNAUTHORIZED => ConnectException::FORBIDDEN,
+        ErrorKind::Command(ref command) if command.code == CODE_USER_NOT_FOUND => ConnectException::NOT_FOUND,
+        ErrorKind::Command(ref command) if command.code == CODE_DUPLICATE_KEY => ConnectException::CONFLICT,
+        ErrorKind::Command(_) => ConnectException::BAD_REQUEST,
+        _ => ConnectException::INTERNAL_ERROR,
+    };
+    ConnectException::from_status(status, error.to_string())
+}
\ No newline at end of file
@@ -1,18 +1,28 @@
-use mongodb::{bson::{doc, oid::ObjectId, Bson, Document}, options::IndexOptions, IndexModel};
-use serde_json::from_str;
+use std::{mem, time::Duration};
+
+use mongodb::{bson::{doc, oid::ObjectId, to_document, Bson, Document}, options::{ClusteredIndex, CreateCollectionOptions, IndexOptions, TimeseriesGranularity, TimeseriesOptions}, IndexModel};
+use serde_json::{from_str, from_value, Value};
 
 use crate::{
     commons::exception::connect_exception::ConnectException,
     domain::{
+        aggregate::{
+            aggregate_accumulator::AggregateAccumulator, aggregate_field::AggregateField,
+            aggregate_pipeline::AggregatePipeline, aggregate_stage::AggregateStage,
+            e_aggregate_accumulator::EAggregateAccumulator,
+        },
+        collection::{collection_options::CollectionOptions, e_collection_category::ECollectionCategory},
         field::{e_field_code::EFieldCode, generate::field_data::FieldData},
         filter::{
-            e_filter_category::EFilterCategory, filter_element::FilterElement,
-            filter_value::FilterValue,
+            e_filter_category::EFilterCategory, e_filter_operator::EFilterOperator,
+            filter_element::FilterElement, filter_value::FilterValue,
         },
     },
 };
 
-use super::e_filter_attributes::EFilterAtributtes;
+use super::{e_filter_attributes::EFilterAtributtes, mongo_db_actions::index_collation, mongo_db_shell};
+
+const WRITE_STAGES: [&str; 2] = ["$out", "$merge"];
 
 pub struct QueryItems {
     and_fields: Vec<Document>,
@@ -23,10 +33,20 @@ pub struct QueryItems {
 }
 
 impl FilterElement {
+
+    pub fn has_write_stage(&self) -> bool {
+        let value = self.value();
+        match value.category() {
+            EFilterCategory::QUERY => query_as_pipeline(&value.value())
+                .is_ok_and(|p| p.iter().any(|d| WRITE_STAGES.iter().any(|s| d.contains_key(s)))),
+            EFilterCategory::ROOT | EFilterCategory::COLLECTION => value.children().iter().any(|c| c.has_write_stage()),
+            _ => false,
+        }
+    }
     
     pub fn as_mongo_agregate(&self) -> Result<Vec<Document>, ConnectException> {
         let mut registry = QueryItems {and_fields: Vec::new(), or_fields: Vec::new(), queries: Vec::new(), projections: doc!{}, add_fields: doc!{}};
-        registry = self.make_agregate(registry);
+        registry = self.make_agregate(registry)?;
 
         let mut matches_collection = doc! {};
 
@@ -59,37 +79,50 @@ impl FilterElement {
         Ok(pipeline)
     }
 
-    fn make_agregate(&self, mut registry: QueryItems) -> QueryItems {
+    fn make_agregate(&self, mut registry: QueryItems) -> Result<QueryItems, ConnectException> {
         let f_value = self.value();
+        if f_value.category() == EFilterCategory::COLLECTION {
+            return self.make_collection(registry);
+        }
+
         let mut field = self.field();
 
-        let result = f_value.as_mongo_agregate(&field, registry);
+        let result = f_value.as_mongo_agregate(&field, registry)?;
         let value = result.0;
         registry = result.1;
         field = result.2;
 
         match f_value.category() {
-            EFilterCategory::ROOT => registry,
-            EFilterCategory::COLLECTION => self.make_collection(registry),
-            EFilterCategory::QUERY => self.make_query(registry, value),
-            _ => self.make_base(registry, field, value)
+            EFilterCategory::ROOT | EFilterCategory::COLLECTION => Ok(registry),
+            EFilterCategory::QUERY => Ok(self.make_query(registry, value)),
+            _ => Ok(self.make_base(registry, field, value))
         }
     }
 
-    fn make_collection(&self, mut registry: QueryItems) -> QueryItems {
+    fn make_collection(&self, mut registry: QueryItems) -> Result<QueryItems, ConnectException> {
+        let and_fields = mem::take(&mut registry.and_fields);
+        let or_fields = mem::take(&mut registry.or_fields);
+
+        registry = self.value().as_mongo_agregate(&self.field(), registry)?.1;
+
         let mut block = doc! {};
 
         if !registry.and_fields.is_empty() {
-            block.insert("$and",  registry.and_fields.clone());
-            registry.and_fields.clear();
+            block.insert("$and", mem::take(&mut registry.and_fields));
         }
 
-        if !registry.and_fields.is_empty() {
-            block.insert("$or",  registry.or_fields.clone());
-            registry.or_fields.clear();
+        if !registry.or_fields.is_empty() {
+            block.insert("$or", mem::take(&mut registry.or_fields));
         }
 
+        registry.and_fields = and_fields;
+        registry.or_fields = or_fields;
+
         if !block.is_empty() {
+            if self.is_negate() {
+                block = doc! { "$nor": [block] };
+            }
+
             if self.is_or() {
                 registry.or_fields.push(block);
             } else {
@@ -97,34 +130,31 @@ impl FilterElement {
             }   
         }
 
-        return registry;
+        Ok(registry)
     }
 
     fn make_query(&self, mut registry: QueryItems, value: Bson) -> QueryItems {
-        let array = value.as_array();
-        //TODO: Error
-        let mut documents = Vec::new();
-        for bson in array.unwrap() {
-            let document = bson.as_document();
-            //TODO: Error
-            documents.push(document.cloned().unwrap());
-        }
-        registry.queries.append(&mut documents);
+        if let Bson::Array(array) = value {
+            registry.queries.extend(array.into_iter().filter_map(|b| match b {
+                Bson::Document(document) => Some(document),
+                _ => None,
+            }));
+        }
         return registry;    
     }
 
     fn make_base(&self, mut registry: QueryItems, field: String, value: Bson) -> QueryItems {
+        let operator = self.value().operator();
+
         let query;
         if self.is_negate() {
             query = doc! {
                 field: {
-                    "$not": {
-                        "$eq": value
-                    }
+                    "$not": operator_as_mongo(operator, value, true)
                 }
             };
         } else {
-            query = doc! {field: value};
+            query = doc! {field: operator_as_mongo(operator, value, false)};
         }
 
         if self.is_or() {
@@ -138,21 +168,71 @@ impl FilterElement {
 
 }
 
+fn operator_as_mongo(operator: EFilterOperator, value: Bson, negate: bool) -> Bson {
+    let code = match operator {
+        EFilterOperator::EQ if negate => "$eq",
+        EFilterOperator::EQ => return value,
+        EFilterOperator::NE => "$ne",
+        EFilterOperator::GT => "$gt",
+        EFilterOperator::GTE => "$gte",
+        EFilterOperator::LT => "$lt",
+        EFilterOperator::LTE => "$lte",
+        EFilterOperator::LIKE => return match value {
+            Bson::String(pattern) => Bson::Document(doc! { "$regex": like_as_regex(&pattern) }),
+            value => value,
+        },
+    };
+    Bson::Document(doc! { code: value })
+}
+
+fn like_as_regex(pattern: &str) -> String {
+    let mut regex = String::from("^");
+    for c in pattern.chars() {
+        match c {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '\\' | '.' | '^' | '$' | '|' | '?' | '*' | '+' | '(' | ')' | '[' | ']' | '{' | '}' => {
+                regex.push('\\');
+                regex.push(c);
+            },
+            _ => regex.push(c),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+pub(crate) fn query_as_pipeline(value: &str) -> Result<Vec<Document>, ConnectException> {
+    let pipeline: Result<Vec<Document>, serde_json::Error> = match from_str(value) {
+        Ok(pipeline) => Ok(pipeline),
+        Err(error) => match mongo_db_shell::parse_json(value) {
+            Ok(relaxed) => from_value(relaxed),
+            Err(_) => Err(error),
+        },
+    };
+
+    match pipeline {
+        Ok(pipeline) => Ok(pipeline),
+        Err(error) => Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Invalid query pipeline: {}.", error))),
+    }
+}
+
 impl FilterValue {
  
-    pub fn as_mongo_agregate(&self, field: &String, registry: QueryItems) -> (Bson, QueryItems, String) {
+    pub fn as_mongo_agregate(&self, field: &String, registry: QueryItems) -> Result<(Bson, QueryItems, String), ConnectException> {
         match self.category() {
             EFilterCategory::ID_NUMERIC | EFilterCategory::ID_STRING => self.id_as_mongo_agregate(field, registry),
             EFilterCategory::QUERY => self.query_as_mongo_agregate(field, registry),
             EFilterCategory::STRING => self.string_as_mongo_agregate(field, registry),
             EFilterCategory::BOOLEAN => self.boolean_as_mongo_agregate(field, registry),
             EFilterCategory::NUMERIC => self.integer_as_mongo_agregate(field, registry),
+            EFilterCategory::PARAMETER => self.parameter_as_mongo_agregate(field, registry),
             EFilterCategory::COLLECTION => self.collection_as_mongo_agregate(field, registry),
             EFilterCategory::ROOT => self.collection_as_mongo_agregate(field, registry),
         }
     }
 
-    pub fn id_as_mongo_agregate(&self, field: &String, mut registry: QueryItems) -> (Bson, QueryItems, String) {
+    pub fn id_as_mongo_agregate(&self, field: &String, mut registry: QueryItems) -> Result<(Bson, QueryItems, String), ConnectException> {
         let attributes = self.attributes();
         let mut value = Bson::String(self.value());
         
@@ -180,18 +260,15 @@ impl FilterValue {
             }
         }
         
-        (value, registry, field_fix)
+        Ok((value, registry, field_fix))
     }
 
-    pub fn query_as_mongo_agregate(&self, field: &String, registry: QueryItems) -> (Bson, QueryItems, String) {
-        let value = self.value();
-        let pipeline: Result<Vec<Document>, serde_json::Error> = from_str(&value);
-        //TODO: Error
-        let array = pipeline.unwrap().iter().map(|d| Bson::Document(d.clone())).collect();
-        (Bson::Array(array), registry, field.to_owned())
+    pub fn query_as_mongo_agregate(&self, field: &String, registry: QueryItems) -> Result<(Bson, QueryItems, String), ConnectException> {
+        let array = query_as_pipeline(&self.value())?.into_iter().map(Bson::Document).collect();
+        Ok((Bson::Array(array), registry, field.to_owned()))
     }
 
-    pub fn string_as_mongo_agregate(&self, field: &String, registry: QueryItems) -> (Bson, QueryItems, String) {
+    pub fn string_as_mongo_agregate(&self, field: &String, registry: QueryItems) -> Result<(Bson, QueryItems, String), ConnectException> {
         let attributes = self.attributes();
         let mut value = Bson::String(self.value());
 
@@ -203,29 +280,170 @@ impl FilterValue {
             }
         }
 
-        (value, registry, field.to_owned())
+        Ok((value, registry, field.to_owned()))
     }
 
-    pub fn boolean_as_mongo_agregate(&self, field: &String, registry: QueryItems) -> (Bson, QueryItems, String) {
+    pub fn boolean_as_mongo_agregate(&self, field: &String, registry: QueryItems) -> Result<(Bson, QueryItems, String), ConnectException> {
         let value = self.value();
-        let boolean = value.parse::<bool>();
-        //TODO: Error
-        (Bson::Boolean(boolean.unwrap()), registry, field.to_owned())
+        match value.parse::<bool>() {
+            Ok(boolean) => Ok((Bson::Boolean(boolean), registry, field.to_owned())),
+            Err(_) => Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Value '{}' for field '{}' is not a boolean.", value, field))),
+        }
     }
 
-    pub fn integer_as_mongo_agregate(&self, field: &String, registry: QueryItems) -> (Bson, QueryItems, String) {
+    pub fn integer_as_mongo_agregate(&self, field: &String, registry: QueryItems) -> Result<(Bson, QueryItems, String), ConnectException> {
         let value = self.value();
-        let integer = value.parse::<i64>();
-        //TODO: Error
-        (Bson::Int64(integer.unwrap()), registry, field.to_owned())
+        if let Ok(integer) = value.parse::<i64>() {
+            return Ok((Bson::Int64(integer), registry, field.to_owned()));
+        }
+        match value.parse::<f64>() {
+            Ok(float) => Ok((Bson::Double(float), registry, field.to_owned())),
+            Err(_) => Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Value '{}' for field '{}' is not a number.", value, field))),
+        }
+    }
+
+    pub fn parameter_as_mongo_agregate(&self, field: &String, registry: QueryItems) -> Result<(Bson, QueryItems, String), ConnectException> {
+        match self.parameter_name() {
+            Some(name) => Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Parameter '{}' is not bound.", name))),
+            None => self.bind(&self.value()).as_mongo_agregate(field, registry),
+        }
     }
 
-    fn collection_as_mongo_agregate(&self, field: &String, mut registry: QueryItems) -> (Bson, QueryItems, String) {
+    fn collection_as_mongo_agregate(&self, field: &String, mut registry: QueryItems) -> Result<(Bson, QueryItems, String), ConnectException> {
         let value = self.value();
         for child in self.children() {
-            registry = child.make_agregate(registry);
+            registry = child.make_agregate(registry)?;
+        }
+        return Ok((Bson::String(value), registry, field.to_owned()));
+    }
+
+}
+
+impl AggregatePipeline {
+
+    pub fn has_write_stage(&self) -> bool {
+        self.stages().iter().any(|stage| match stage {
+            AggregateStage::MATCH(filter) => filter.has_write_stage(),
+            AggregateStage::FACET(facets) => facets.iter().any(|f| f.pipeline().has_write_stage()),
+            _ => false,
+        })
+    }
+
+    pub fn as_mongo_agregate(&self) -> Result<Vec<Document>, ConnectException> {
+        let mut pipeline = Vec::new();
+        for stage in self.stages() {
+            pipeline.append(&mut stage.as_mongo_agregate()?);
+        }
+        Ok(pipeline)
+    }
+
+}
+
+impl AggregateStage {
+
+    pub fn as_mongo_agregate(&self) -> Result<Vec<Document>, ConnectException> {
+        let stage = match self {
+            AggregateStage::MATCH(filter) => return filter.as_mongo_agregate(),
+            AggregateStage::GROUP { keys, accumulators } => {
+                let id = match keys.len() {
+                    0 => Bson::Null,
+                    1 if keys[0].expression().starts_with('$') => keys[0].expression_as_mongo(),
+                    _ => Bson::Document(AggregateField::collection_as_mongo(keys)),
+                };
+
+                let mut group = doc! { "_id": id };
+                for accumulator in accumulators {
+                    group.insert(accumulator.alias(), accumulator.as_mongo_agregate()?);
+                }
+
+                doc! { "$group": group }
+            },
+            AggregateStage::PROJECT(fields) => doc! { "$project": AggregateField::collection_as_mongo(fields) },
+            AggregateStage::ADD_FIELDS(fields) => doc! { "$addFields": AggregateField::collection_as_mongo(fields) },
+            AggregateStage::UNWIND { field, sw_preserve_empty } => doc! {
+                "$unwind": {
+                    "path": format!("${}", field.trim_start_matches('$')),
+                    "preserveNullAndEmptyArrays": sw_preserve_empty
+                }
+            },
+            AggregateStage::LOOKUP { from, local_field, foreign_field, alias } => doc! {
+                "$lookup": {
+                    "from": from,
+                    "localField": local_field,
+                    "foreignField": foreign_field,
+                    "as": alias
+                }
+            },
+            AggregateStage::SORT(sort) => {
+                let mut order = doc! {};
+                for field in sort {
+                    order.insert(field.field(), if field.is_ascending() { 1 } else { -1 });
+                }
+                doc! { "$sort": order }
+            },
+            AggregateStage::SKIP(skip) => doc! { "$skip": Bson::Int64(*skip as i64) },
+            AggregateStage::LIMIT(limit) => doc! { "$limit": Bson::Int64(*limit as i64) },
+            AggregateStage::FACET(facets) => {
+                let mut facet = doc! {};
+                for item in facets {
+                    facet.insert(item.name(), item.pipeline().as_mongo_agregate()?);
+                }
+                doc! { "$facet": facet }
+            },
+        };
+        Ok(vec![stage])
+    }
+
+}
+
+impl AggregateAccumulator {
+
+    pub fn as_mongo_agregate(&self) -> Result<Document, ConnectException> {
+        let code = match self.operator() {
+            EAggregateAccumulator::COUNT => return Ok(doc! { "$sum": 1 }),
+            EAggregateAccumulator::SUM => "$sum",
+            EAggregateAccumulator::AVG => "$avg",
+            EAggregateAccumulator::MIN => "$min",
+            EAggregateAccumulator::MAX => "$max",
+            EAggregateAccumulator::FIRST => "$first",
+            EAggregateAccumulator::LAST => "$last",
+            EAggregateAccumulator::PUSH => "$push",
+            EAggregateAccumulator::ADD_TO_SET => "$addToSet",
+        };
+
+        let field = match self.field() {
+            Some(field) => field,
+            None => {
+                let message = format!("Accumulator '{}' requires a field.", self.alias());
+                return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, message));
+            },
+        };
+
+        Ok(doc! { code: AggregateField::new(self.alias(), field).expression_as_mongo() })
+    }
+
+}
+
+impl AggregateField {
+
+    fn collection_as_mongo(fields: &[AggregateField]) -> Document {
+        let mut document = doc! {};
+        for field in fields {
+            document.insert(field.alias(), field.expression_as_mongo());
+        }
+        document
+    }
+
+    pub fn expression_as_mongo(&self) -> Bson {
+        let expression = self.expression();
+        if expression.starts_with('$') {
+            return Bson::String(expression);
+        }
+
+        match mongo_db_shell::parse_json(&expression).map(Bson::try_from) {
+            Ok(Ok(bson)) => bson,
+            _ => Bson::String(format!("${}", expression)),
         }
-        return (Bson::String(value), registry, field.to_owned());
     }
 
 }
@@ -279,4 +497,84 @@ impl FieldData {
         Ok(index)
     }
 
+}
+
+impl CollectionOptions {
+
+    pub fn as_mongo_create(&self) -> Result<CreateCollectionOptions, ConnectException> {
+        let mut options = CreateCollectionOptions::default();
+
+        match self.category() {
+            ECollectionCategory::STANDARD => {},
+            ECollectionCategory::CAPPED => {
+                if self.size().is_none() {
+                    return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Capped collections require a size.")));
+                }
+                options.capped = Some(true);
+                options.size = self.size();
+                options.max = self.max();
+            },
+            ECollectionCategory::TIME_SERIES => {
+                let time_field = match self.time_field() {
+                    Some(time_field) if !time_field.is_empty() => time_field,
+                    _ => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Time-series collections require a time field."))),
+                };
+
+                let granularity = match self.granularity().as_deref() {
+                    None | Some("") => None,
+                    Some("seconds") => Some(TimeseriesGranularity::Seconds),
+                    Some("minutes") => Some(TimeseriesGranularity::Minutes),
+                    Some("hours") => Some(TimeseriesGranularity::Hours),
+                    Some(granularity) => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Granularity '{}' not supported.", granularity))),
+                };
+
+                options.timeseries = Some(TimeseriesOptions::builder()
+                    .time_field(time_field)
+                    .meta_field(self.meta_field().filter(|m| !m.is_empty()))
+                    .granularity(granularity)
+                    .build());
+            },
+            ECollectionCategory::CLUSTERED => {
+                options.clustered_index = Some(ClusteredIndex::default());
+            },
+            ECollectionCategory::VIEW => {
+                let source = match self.source() {
+                    Some(source) if !source.is_empty() => source,
+                    _ => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Views require a source collection."))),
+                };
+
+                options.view_on = Some(source);
+                options.pipeline = Some(Self::view_pipeline(&self.pipeline().unwrap_or_default())?);
+            },
+        }
+
+        if let Some(seconds) = self.expire_after_seconds() {
+            if !matches!(self.category(), ECollectionCategory::TIME_SERIES | ECollectionCategory::CLUSTERED) {
+                return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Expiration is only supported by time-series and clustered collections.")));
+            }
+            options.expire_after_seconds = Some(Duration::from_secs(seconds));
+        }
+
+        if let Some(collation) = self.collation().filter(|c| !c.is_empty()) {
+            options.collation = Some(index_collation(&collation)?);
+        }
+
+        Ok(options)
+    }
+
+    fn view_pipeline(pipeline: &str) -> Result<Vec<Document>, ConnectException> {
+        if pipeline.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let stages: Vec<Value> = match from_str(pipeline) {
+            Ok(stages) => stages,
+            Err(error) => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("View pipeline must be a JSON array: {}", error))),
+        };
+
+        stages.iter()
+            .map(|s| to_document(s).map_err(|e| ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Invalid view stage: {}", e))))
+            .collect()
+    }
+
 }
\ No newline at end of file
@@ -16,19 +16,41 @@ pub mod infrastructure {
         pub mod mongo_db {
             pub mod e_action;
             pub mod e_filter_attributes;
+            pub mod extractor_explain_mongo_db;
             pub mod extractor_metadata_mongo_db;
             pub mod mongo_db_actions;
+            pub mod mongo_db_connection;
+            pub mod mongo_db_grid_fs;
+            pub mod mongo_db_operation_actions;
+            pub mod mongo_db_profiler_actions;
             pub mod mongo_db_repository;
+            pub mod mongo_db_shell;
+            pub mod mongo_db_transaction;
+            pub mod mongo_db_user_actions;
             pub mod mongo_utils;
         }
         pub mod db_dictionary;
         pub mod e_db_repository;
         pub mod i_db_repository;
+        pub mod i_db_transaction;
     }
+    pub mod db_health_monitor;
+    pub mod db_query_history;
     pub mod db_service_lite;
     pub mod db_service;
+    pub mod db_session_manager;
+    pub mod db_session;
 }
 pub mod domain {
+    pub mod aggregate {
+        pub mod aggregate_accumulator;
+        pub mod aggregate_facet;
+        pub mod aggregate_field;
+        pub mod aggregate_pipeline;
+        pub mod aggregate_query;
+        pub mod aggregate_stage;
+        pub mod e_aggregate_accumulator;
+    }
     pub mod action {
         pub mod definition {
             pub mod action_form_collection;
@@ -43,9 +65,16 @@ pub mod domain {
             pub mod form_field;
         }
     }
+    pub mod change {
+        pub mod change_event;
+        pub mod e_change_operation;
+        pub mod watch_query;
+    }
     pub mod collection {
         pub mod collection_data;
         pub mod collection_definition;
+        pub mod collection_options;
+        pub mod e_collection_category;
         pub mod generate_collection_query;
     }
     pub mod data_base {
@@ -70,6 +99,11 @@ pub mod domain {
         }
         pub mod e_field_code;
     }
+    pub mod file {
+        pub mod file_bucket;
+        pub mod file_data;
+        pub mod file_query;
+    }
     pub mod filter {
         pub mod definition {
             pub mod filter_attribute_default_definition;
@@ -79,11 +113,32 @@ pub mod domain {
         pub mod collection_query;
         pub mod data_base_query;
         pub mod document_query;
+        pub mod document_sort;
         pub mod e_filter_category;
+        pub mod e_filter_operator;
+        pub mod filter_parser;
         pub mod filter_value;
         pub mod filter_value_attribute;
         pub mod filter_element;
     }
+    pub mod health {
+        pub mod health_record;
+        pub mod service_health;
+    }
+    pub mod operation {
+        pub mod operation_data;
+        pub mod operation_query;
+    }
+    pub mod permission {
+        pub mod e_service_role;
+        pub mod service_grant;
+        pub mod service_user;
+    }
+    pub mod query {
+        pub mod query_explain;
+        pub mod query_record;
+        pub mod saved_query;
+    }
     pub mod table {
         pub mod definition {
             pub mod table_definition;
@@ -95,9 +150,28 @@ pub mod domain {
             pub mod table_data_group;
         }
     }
+    pub mod transaction {
+        pub mod document_transaction;
+        pub mod transaction_operation;
+    }
     pub mod e_json_type;
     pub mod connection_data;
 }
 pub mod service {
     pub mod service;
+}
+#[cfg(feature = "server")]
+pub mod server {
+    pub mod handler {
+        pub mod change_handler;
+        pub mod collection_handler;
+        pub mod data_base_handler;
+        pub mod document_handler;
+        pub mod file_handler;
+        pub mod query_handler;
+        pub mod service_handler;
+    }
+    pub mod server_context;
+    pub mod server_response;
+    pub mod server;
 }
\ No newline at end of file
@@ -1,11 +1,50 @@
+mod cli {
+    pub mod args;
+    pub mod command;
+}
+
+mod tui {
+    pub mod app;
+    pub mod terminal;
+    pub mod view;
+    pub mod widget;
+}
+
 use dotenv::dotenv;
 
-use rust_db_manager_core::commons::configuration::configuration::Configuration;
+use rust_db_manager_core::{
+    commons::configuration::configuration::Configuration,
+    domain::permission::service_user::ServiceUser,
+};
+
+use tui::app::App;
 
 #[tokio::main]
 async fn main() {
     dotenv().ok();
 
     let _ = Configuration::initialize();
-    println!("rust-db-manager!");
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        std::process::exit(cli::command::run(args).await);
+    }
+
+    let result = match App::open() {
+        Ok(mut app) => app.run().await,
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        eprintln!("rust-db-manager: {}", error);
+    }
+}
+
+pub fn local_user() -> ServiceUser {
+    let envs = Configuration::os_env_args();
+    let name = envs.get("USER")
+        .or(envs.get("USERNAME"))
+        .cloned()
+        .unwrap_or(String::from("local"));
+    ServiceUser::new(name, Vec::new())
 }
\ No newline at end of file
@@ -0,0 +1,69 @@
+use std::convert::Infallible;
+
+use axum::{extract::{Path, Query}, http::HeaderMap, response::sse::{Event, KeepAlive, Sse}};
+use futures_util::stream::{BoxStream, StreamExt};
+use serde::Deserialize;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::change::{e_change_operation::EChangeOperation, watch_query::WatchQuery},
+    server::server_context,
+};
+
+const LAST_EVENT_ID: &str = "Last-Event-ID";
+
+#[derive(Deserialize)]
+pub struct WatchRequest {
+    operations: Option<String>,
+    resume: Option<String>,
+    #[serde(default)]
+    pre_image: bool
+}
+
+impl WatchRequest {
+
+    fn query(self, headers: &HeaderMap, data_base: String, collection: Option<String>) -> Result<WatchQuery, ConnectException> {
+        let mut operations = Vec::new();
+        for code in self.operations.unwrap_or_default().split(',').map(|c| c.trim()).filter(|c| !c.is_empty()) {
+            match EChangeOperation::from_string(&code.to_uppercase()) {
+                Some(operation) => operations.push(operation),
+                None => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, format!("Unknown change operation '{}'.", code))),
+            }
+        }
+
+        let last_event = headers.get(LAST_EVENT_ID)
+            .and_then(|h| h.to_str().ok())
+            .map(String::from);
+
+        Ok(WatchQuery::from(data_base, collection, operations, self.resume.or(last_event), self.pre_image))
+    }
+
+}
+
+pub async fn watch_data_base(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>, Query(request): Query<WatchRequest>) -> Result<Sse<BoxStream<'static, Result<Event, Infallible>>>, ConnectException> {
+    let query = request.query(&headers, data_base, None)?;
+    watch(&headers, &service, &query).await
+}
+
+pub async fn watch_collection(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Query(request): Query<WatchRequest>) -> Result<Sse<BoxStream<'static, Result<Event, Infallible>>>, ConnectException> {
+    let query = request.query(&headers, data_base, Some(collection))?;
+    watch(&headers, &service, &query).await
+}
+
+async fn watch(headers: &HeaderMap, service: &str, query: &WatchQuery) -> Result<Sse<BoxStream<'static, Result<Event, Infallible>>>, ConnectException> {
+    let service = server_context::instance(headers, service).await?;
+
+    let stream = service.watch(query).await?.map(|r_event| {
+        let event = match r_event {
+            Ok(change) => Event::default()
+                .event(change.operation().to_string())
+                .id(change.resume_token())
+                .json_data(&change)
+                .unwrap_or_else(|e| Event::default().event("error").data(e.to_string())),
+            Err(error) => Event::default().event("error").data(error.message()),
+        };
+        Ok(event)
+    });
+
+    Ok(Sse::new(stream.boxed()).keep_alive(KeepAlive::default()))
+}
\ No newline at end of file
@@ -0,0 +1,118 @@
+use axum::{extract::{Path, Query}, http::{HeaderMap, StatusCode}, Json};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        action::{definition::action_definition::ActionDefinition, generate::action::Action},
+        collection::{collection_options::CollectionOptions, generate_collection_query::GenerateCollectionQuery},
+        document::{document_data::DocumentData, document_schema::DocumentSchema},
+        field::generate::field_data::FieldData,
+        filter::{collection_query::CollectionQuery, data_base_query::DataBaseQuery},
+        table::{definition::table_definition::TableDefinition, group::table_data_group::TableDataGroup},
+    },
+    server::server_context,
+};
+
+#[derive(Deserialize)]
+pub struct CollectionRequest {
+    collection: String,
+    #[serde(default)]
+    fields: Vec<FieldData>,
+    #[serde(default)]
+    options: CollectionOptions
+}
+
+#[derive(Deserialize)]
+pub struct ValidateRequest {
+    #[serde(default)]
+    full: bool
+}
+
+#[derive(Deserialize)]
+pub struct RenameRequest {
+    name: String
+}
+
+pub async fn find_all(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>) -> Result<Json<Vec<String>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_find_all(&DataBaseQuery::from(data_base)).await?))
+}
+
+pub async fn insert(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>, Json(request): Json<CollectionRequest>) -> Result<(StatusCode, Json<String>), ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = GenerateCollectionQuery::from_options(data_base, request.collection, request.fields, request.options);
+    Ok((StatusCode::CREATED, Json(service.collection_create(&query).await?)))
+}
+
+pub async fn delete(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>) -> Result<Json<String>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = GenerateCollectionQuery::from_collection(data_base, collection);
+    Ok(Json(service.collection_drop(&query).await?))
+}
+
+pub async fn exists(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>) -> Result<Json<bool>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_exists(&CollectionQuery::from(data_base, collection)).await?))
+}
+
+pub async fn rename(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(request): Json<RenameRequest>) -> Result<Json<String>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = CollectionQuery::from(data_base, collection);
+    Ok(Json(service.collection_rename(&query, &request.name).await?))
+}
+
+pub async fn metadata(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>) -> Result<Json<Vec<TableDataGroup>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_metadata(&CollectionQuery::from(data_base, collection)).await?))
+}
+
+pub async fn information(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>) -> Result<Json<Vec<TableDefinition>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_information(&CollectionQuery::from(data_base, collection)).await?))
+}
+
+pub async fn schema(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>) -> Result<Json<DocumentSchema>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.schema(&CollectionQuery::from(data_base, collection)).await?))
+}
+
+pub async fn actions(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>) -> Result<Json<Vec<ActionDefinition>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_actions(&CollectionQuery::from(data_base, collection)).await?))
+}
+
+pub async fn action(headers: HeaderMap, Path((service, data_base, collection, code)): Path<(String, String, String, String)>) -> Result<Json<ActionDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    match service.collection_action(&CollectionQuery::from(data_base, collection), &code).await? {
+        Some(action) => Ok(Json(action)),
+        None => Err(ConnectException::from_status(ConnectException::NOT_FOUND, format!("Action '{}' not found.", code))),
+    }
+}
+
+pub async fn execute_action(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(action): Json<Action>) -> Result<Json<String>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_execute_action(&CollectionQuery::from(data_base, collection), &action).await?))
+}
+
+pub async fn validate(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Query(request): Query<ValidateRequest>) -> Result<Json<TableDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_validate(&CollectionQuery::from(data_base, collection), request.full).await?))
+}
+
+pub async fn storage(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>) -> Result<Json<TableDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_storage(&CollectionQuery::from(data_base, collection)).await?))
+}
+
+pub async fn export(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>) -> Result<Json<Vec<DocumentData>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_export(&CollectionQuery::from(data_base, collection)).await?))
+}
+
+pub async fn import(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(documents): Json<Vec<Value>>) -> Result<Json<String>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let documents = documents.iter().map(|d| d.to_string()).collect();
+    Ok(Json(service.collection_import(&CollectionQuery::from(data_base, collection), documents).await?))
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use axum::{extract::Path, http::{HeaderMap, StatusCode}, Json};
+use serde::Deserialize;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        action::{definition::action_definition::ActionDefinition, generate::action::Action},
+        collection::collection_definition::CollectionDefinition,
+        data_base::generate_database_query::GenerateDatabaseQuery,
+        filter::{data_base_query::DataBaseQuery, definition::filter_definition::FilterDefinition},
+        table::{definition::table_definition::TableDefinition, group::table_data_group::TableDataGroup},
+    },
+    server::server_context,
+};
+
+#[derive(Deserialize)]
+pub struct DataBaseRequest {
+    data_base: String
+}
+
+pub async fn find_all(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<Vec<String>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.data_base_find_all().await?))
+}
+
+pub async fn insert(headers: HeaderMap, Path(service): Path<String>, Json(request): Json<DataBaseRequest>) -> Result<(StatusCode, Json<String>), ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = GenerateDatabaseQuery::new(request.data_base);
+    Ok((StatusCode::CREATED, Json(service.data_base_create(&query).await?)))
+}
+
+pub async fn delete(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>) -> Result<Json<String>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = GenerateDatabaseQuery::new(data_base);
+    Ok(Json(service.data_base_drop(&query).await?))
+}
+
+pub async fn exists(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>) -> Result<Json<bool>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.data_base_exists(&DataBaseQuery::from(data_base)).await?))
+}
+
+pub async fn metadata(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>) -> Result<Json<Vec<TableDataGroup>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.data_base_metadata(&DataBaseQuery::from(data_base)).await?))
+}
+
+pub async fn actions(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>) -> Result<Json<Vec<ActionDefinition>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.data_base_actions(&DataBaseQuery::from(data_base)).await?))
+}
+
+pub async fn action(headers: HeaderMap, Path((service, data_base, code)): Path<(String, String, String)>) -> Result<Json<ActionDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    match service.data_base_action(&DataBaseQuery::from(data_base), &code).await? {
+        Some(action) => Ok(Json(action)),
+        None => Err(ConnectException::from_status(ConnectException::NOT_FOUND, format!("Action '{}' not found.", code))),
+    }
+}
+
+pub async fn execute_action(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>, Json(action): Json<Action>) -> Result<Json<String>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.data_base_execute_action(&DataBaseQuery::from(data_base), &action).await?))
+}
+
+pub async fn profile(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>) -> Result<Json<TableDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.data_base_profile(&DataBaseQuery::from(data_base)).await?))
+}
+
+pub async fn collection_schema(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<CollectionDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.collection_accept_schema().await?))
+}
+
+pub async fn filter_schema(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<FilterDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.filter_schema().await?))
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+use axum::{extract::Path, http::{HeaderMap, StatusCode}, Json};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        aggregate::{aggregate_pipeline::AggregatePipeline, aggregate_query::AggregateQuery},
+        collection::collection_data::CollectionData,
+        document::document_data::DocumentData,
+        filter::{collection_query::CollectionQuery, document_query::DocumentQuery, document_sort::DocumentSort, filter_element::FilterElement, filter_parser::FilterParser},
+        query::query_explain::QueryExplain,
+        table::definition::table_definition::TableDefinition,
+        transaction::document_transaction::DocumentTransaction,
+    },
+    server::server_context,
+};
+
+#[derive(Deserialize)]
+pub struct DocumentFilterRequest {
+    skip: Option<usize>,
+    limit: Option<usize>,
+    filter: Option<FilterElement>,
+    #[serde(default)]
+    sort: Vec<DocumentSort>
+}
+
+#[derive(Deserialize)]
+pub struct DocumentUpdateRequest {
+    filter: FilterElement,
+    document: Value
+}
+
+#[derive(Deserialize)]
+pub struct QueryRequest {
+    query: String
+}
+
+#[derive(Deserialize)]
+pub struct DocumentDeleteRequest {
+    filter: FilterElement
+}
+
+impl DocumentFilterRequest {
+
+    fn query(self, data_base: String, collection: String) -> DocumentQuery {
+        DocumentQuery::from_sort(data_base, collection, self.skip, self.limit, self.filter, self.sort)
+    }
+
+}
+
+pub async fn shell(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>, Json(request): Json<QueryRequest>) -> Result<Json<CollectionData>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = service.parse_query(&data_base, &request.query).await?;
+    Ok(Json(service.find_query(&query).await?))
+}
+
+pub async fn transaction(headers: HeaderMap, Path(service): Path<String>, Json(transaction): Json<DocumentTransaction>) -> Result<Json<Vec<Vec<DocumentData>>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.transaction(&transaction).await?))
+}
+
+pub async fn explain(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(request): Json<DocumentFilterRequest>) -> Result<Json<QueryExplain>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.explain(&request.query(data_base, collection)).await?))
+}
+
+pub async fn aggregate(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(pipeline): Json<AggregatePipeline>) -> Result<Json<Vec<DocumentData>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = AggregateQuery::from(data_base, collection, pipeline);
+    Ok(Json(service.aggregate(&query).await?))
+}
+
+pub async fn aggregate_table(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(pipeline): Json<AggregatePipeline>) -> Result<Json<TableDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = AggregateQuery::from(data_base, collection, pipeline);
+    Ok(Json(service.aggregate_table(&query).await?))
+}
+
+pub async fn find_where(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(request): Json<QueryRequest>) -> Result<Json<CollectionData>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = FilterParser::parse(data_base, collection, &request.query)?;
+    Ok(Json(service.find_query(&query).await?))
+}
+
+pub async fn find_query(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(request): Json<DocumentFilterRequest>) -> Result<Json<CollectionData>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.find_query(&request.query(data_base, collection)).await?))
+}
+
+pub async fn find_all(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(request): Json<DocumentFilterRequest>) -> Result<Json<CollectionData>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.find_all(&request.query(data_base, collection)).await?))
+}
+
+pub async fn find(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(request): Json<DocumentFilterRequest>) -> Result<Json<DocumentData>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    match service.find(&request.query(data_base, collection)).await? {
+        Some(document) => Ok(Json(document)),
+        None => Err(ConnectException::from_status(ConnectException::NOT_FOUND, String::from("Document not found."))),
+    }
+}
+
+pub async fn insert(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(document): Json<Value>) -> Result<(StatusCode, Json<DocumentData>), ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = CollectionQuery::from(data_base, collection);
+    Ok((StatusCode::CREATED, Json(service.insert(&query, &document.to_string()).await?)))
+}
+
+pub async fn update(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(request): Json<DocumentUpdateRequest>) -> Result<Json<Vec<DocumentData>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = DocumentQuery::from_filter(data_base, collection, request.filter);
+    Ok(Json(service.update(&query, &request.document.to_string()).await?))
+}
+
+pub async fn delete(headers: HeaderMap, Path((service, data_base, collection)): Path<(String, String, String)>, Json(request): Json<DocumentDeleteRequest>) -> Result<Json<Vec<DocumentData>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = DocumentQuery::from_filter(data_base, collection, request.filter);
+    Ok(Json(service.delete(&query).await?))
+}
\ No newline at end of file
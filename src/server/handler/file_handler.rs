@@ -0,0 +1,82 @@
+use axum::{body::Bytes, extract::{Path, Query}, http::{header, HeaderMap, StatusCode}, Json};
+use futures_util::io::Cursor;
+use serde::Deserialize;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        file::{file_bucket::FileBucket, file_data::FileData, file_query::FileQuery},
+        filter::data_base_query::DataBaseQuery,
+        table::definition::table_definition::TableDefinition,
+    },
+    server::server_context,
+};
+
+#[derive(Deserialize)]
+pub struct FileRequest {
+    name: Option<String>,
+    metadata: Option<String>
+}
+
+#[derive(Deserialize)]
+pub struct RenameRequest {
+    name: String
+}
+
+pub async fn buckets(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>) -> Result<Json<Vec<FileBucket>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.file_buckets(&DataBaseQuery::from(data_base)).await?))
+}
+
+pub async fn buckets_table(headers: HeaderMap, Path((service, data_base)): Path<(String, String)>) -> Result<Json<TableDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.file_buckets_table(&DataBaseQuery::from(data_base)).await?))
+}
+
+pub async fn find_all(headers: HeaderMap, Path((service, data_base, bucket)): Path<(String, String, String)>, Query(request): Query<FileRequest>) -> Result<Json<Vec<FileData>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = FileQuery::from_filename(data_base, bucket, request.name);
+    Ok(Json(service.file_find_all(&query).await?))
+}
+
+pub async fn table(headers: HeaderMap, Path((service, data_base, bucket)): Path<(String, String, String)>, Query(request): Query<FileRequest>) -> Result<Json<TableDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = FileQuery::from_filename(data_base, bucket, request.name);
+    Ok(Json(service.file_table(&query).await?))
+}
+
+pub async fn upload(headers: HeaderMap, Path((service, data_base, bucket)): Path<(String, String, String)>, Query(request): Query<FileRequest>, body: Bytes) -> Result<(StatusCode, Json<FileData>), ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+
+    let filename = match request.name {
+        Some(filename) => filename,
+        None => return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Query parameter 'name' is required."))),
+    };
+
+    let query = FileQuery::from(data_base, bucket);
+    let mut source = Cursor::new(body.to_vec());
+    Ok((StatusCode::CREATED, Json(service.file_upload(&query, &filename, request.metadata.as_deref(), &mut source).await?)))
+}
+
+pub async fn download(headers: HeaderMap, Path((service, data_base, bucket, id)): Path<(String, String, String, String)>) -> Result<([(header::HeaderName, String); 2], Vec<u8>), ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    let query = FileQuery::from(data_base, bucket);
+
+    let mut destination = Cursor::new(Vec::new());
+    service.file_download(&query, &id, &mut destination).await?;
+
+    Ok(([
+        (header::CONTENT_TYPE, String::from("application/octet-stream")),
+        (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", id)),
+    ], destination.into_inner()))
+}
+
+pub async fn delete(headers: HeaderMap, Path((service, data_base, bucket, id)): Path<(String, String, String, String)>) -> Result<Json<String>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.file_delete(&FileQuery::from(data_base, bucket), &id).await?))
+}
+
+pub async fn rename(headers: HeaderMap, Path((service, data_base, bucket, id)): Path<(String, String, String, String)>, Json(request): Json<RenameRequest>) -> Result<Json<String>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.file_rename(&FileQuery::from(data_base, bucket), &id, &request.name).await?))
+}
\ No newline at end of file
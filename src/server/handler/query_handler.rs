@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use axum::{extract::Path, http::{HeaderMap, StatusCode}, Json};
+use serde::Deserialize;
+
+use crate::{
+    commons::{configuration::configuration::Configuration, exception::connect_exception::ConnectException},
+    domain::{
+        collection::collection_data::CollectionData,
+        filter::document_query::DocumentQuery,
+        permission::e_service_role::EServiceRole,
+        query::{query_record::QueryRecord, saved_query::SavedQuery},
+    },
+    infrastructure::db_query_history::DBQueryHistory,
+    server::server_context,
+};
+
+#[derive(Deserialize)]
+pub struct SavedQueryRequest {
+    name: String,
+    #[serde(default)]
+    description: String,
+    query: DocumentQuery
+}
+
+#[derive(Deserialize)]
+pub struct RunQueryRequest {
+    #[serde(default)]
+    parameters: HashMap<String, String>
+}
+
+pub async fn find_all(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<Vec<SavedQuery>>, ConnectException> {
+    let (service, _) = server_context::session(&headers, &service, EServiceRole::VIEWER)?;
+    Ok(Json(service.queries()))
+}
+
+pub async fn find(headers: HeaderMap, Path((service, name)): Path<(String, String)>) -> Result<Json<SavedQuery>, ConnectException> {
+    let (service, _) = server_context::session(&headers, &service, EServiceRole::VIEWER)?;
+    match service.saved_query(&name) {
+        Some(query) => Ok(Json(query)),
+        None => Err(ConnectException::from_status(ConnectException::NOT_FOUND, format!("Query '{}' not found.", name))),
+    }
+}
+
+pub async fn insert(headers: HeaderMap, Path(service): Path<String>, Json(request): Json<SavedQueryRequest>) -> Result<(StatusCode, Json<SavedQuery>), ConnectException> {
+    let (mut service, session) = server_context::session(&headers, &service, EServiceRole::VIEWER)?;
+    let user = session.user();
+    let query = SavedQuery::new(request.name, user.name(), request.description, request.query);
+    service.save_query(&user, query.clone())?;
+    Configuration::put_service(service)?;
+    Ok((StatusCode::CREATED, Json(query)))
+}
+
+pub async fn delete(headers: HeaderMap, Path((service, name)): Path<(String, String)>) -> Result<Json<SavedQuery>, ConnectException> {
+    let (mut service, session) = server_context::session(&headers, &service, EServiceRole::VIEWER)?;
+    let user = session.user();
+    let query = match service.remove_query(&user, &name)? {
+        Some(query) => query,
+        None => return Err(ConnectException::from_status(ConnectException::NOT_FOUND, format!("Query '{}' not found.", name))),
+    };
+    Configuration::put_service(service)?;
+    Ok(Json(query))
+}
+
+pub async fn run(headers: HeaderMap, Path((service, name)): Path<(String, String)>, Json(request): Json<RunQueryRequest>) -> Result<Json<CollectionData>, ConnectException> {
+    let (definition, _) = server_context::session(&headers, &service, EServiceRole::VIEWER)?;
+    let query = match definition.saved_query(&name) {
+        Some(query) => query.bind(&request.parameters)?,
+        None => return Err(ConnectException::from_status(ConnectException::NOT_FOUND, format!("Query '{}' not found.", name))),
+    };
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.find_query(&query).await?))
+}
+
+pub async fn history(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<Vec<QueryRecord>>, ConnectException> {
+    let (service, _) = server_context::session(&headers, &service, EServiceRole::VIEWER)?;
+    Ok(Json(DBQueryHistory::history(&service.name())))
+}
\ No newline at end of file
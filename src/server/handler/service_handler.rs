@@ -0,0 +1,175 @@
+use axum::{extract::{Path, Query}, http::{HeaderMap, StatusCode}, Json};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    commons::{configuration::configuration::Configuration, exception::connect_exception::ConnectException},
+    domain::{action::{definition::action_definition::ActionDefinition, generate::action::Action}, connection_data::ConnectionData, operation::{operation_data::OperationData, operation_query::OperationQuery}, permission::{e_service_role::EServiceRole, service_grant::ServiceGrant}, table::{definition::table_definition::TableDefinition, group::table_data_group::TableDataGroup}},
+    infrastructure::{db_service::DBService, db_service_lite::DBServiceLite, db_session::DBSession, db_session_manager::DBSessionManager, repository::e_db_repository::EDBRepository},
+    server::server_context,
+};
+
+#[derive(Deserialize)]
+pub struct ServiceRequest {
+    name: String,
+    category: EDBRepository,
+    connection: String,
+    password: Option<String>,
+    #[serde(default)]
+    read_only: bool
+}
+
+#[derive(Deserialize)]
+pub struct SessionRequest {
+    #[serde(default)]
+    password: String
+}
+
+#[derive(Deserialize)]
+pub struct ReadOnlyRequest {
+    read_only: bool
+}
+
+#[derive(Serialize)]
+pub struct ServiceResponse {
+    name: String,
+    owner: String,
+    category: EDBRepository,
+    connection: String,
+    protected: bool,
+    read_only: bool,
+    timestamp: u128,
+    grants: Vec<ServiceGrant>
+}
+
+impl ServiceResponse {
+
+    fn from(service: &DBService) -> Self {
+        Self {
+            name: service.name(),
+            owner: service.owner(),
+            category: service.category(),
+            connection: service.connection_data().masked(),
+            protected: service.is_protected(),
+            read_only: service.is_read_only(),
+            timestamp: service.timestamp(),
+            grants: service.grants()
+        }
+    }
+
+}
+
+pub async fn find_all(headers: HeaderMap) -> Result<Json<Vec<DBServiceLite>>, ConnectException> {
+    let user = server_context::caller(&headers)?;
+    Ok(Json(Configuration::find_services_as(&user)?))
+}
+
+pub async fn find(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<ServiceResponse>, ConnectException> {
+    let (service, _) = server_context::session(&headers, &service, EServiceRole::VIEWER)?;
+    Ok(Json(ServiceResponse::from(&service)))
+}
+
+pub async fn insert(headers: HeaderMap, Json(request): Json<ServiceRequest>) -> Result<(StatusCode, Json<ServiceResponse>), ConnectException> {
+    let user = server_context::caller(&headers)?;
+
+    let connection_data = ConnectionData::parse(request.category, request.connection)?;
+
+    let protected = request.password.is_some();
+    let password = request.password.unwrap_or_default();
+
+    let mut service = DBService::new(request.name, user.name(), protected, password, connection_data)?;
+    service.set_read_only(request.read_only);
+
+    if let Err(error) = Configuration::push_service(&service) {
+        return Err(ConnectException::from_status(ConnectException::CONFLICT, error.message()));
+    }
+
+    Ok((StatusCode::CREATED, Json(ServiceResponse::from(&service))))
+}
+
+pub async fn delete(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<ServiceResponse>, ConnectException> {
+    let (service, _) = server_context::session(&headers, &service, EServiceRole::ADMIN)?;
+    Configuration::remove_service(service.clone())?;
+    Ok(Json(ServiceResponse::from(&service)))
+}
+
+pub async fn read_only(headers: HeaderMap, Path(service): Path<String>, Json(request): Json<ReadOnlyRequest>) -> Result<Json<ServiceResponse>, ConnectException> {
+    let (mut service, _) = server_context::session(&headers, &service, EServiceRole::ADMIN)?;
+    service.set_read_only(request.read_only);
+    Configuration::put_service(service.clone())?;
+    Ok(Json(ServiceResponse::from(&service)))
+}
+
+pub async fn session_open(headers: HeaderMap, Path(service): Path<String>, Json(request): Json<SessionRequest>) -> Result<(StatusCode, Json<DBSession>), ConnectException> {
+    let user = server_context::caller(&headers)?;
+    let service = server_context::find_service(&service, &user, EServiceRole::VIEWER)?;
+    server_context::check_session_open(&service)?;
+    Ok((StatusCode::CREATED, Json(service.authorize(&user, request.password)?)))
+}
+
+pub async fn session_close(headers: HeaderMap, Path(service): Path<String>) -> Result<StatusCode, ConnectException> {
+    let (_, session) = server_context::session(&headers, &service, EServiceRole::VIEWER)?;
+    DBSessionManager::revoke(&session.token());
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn grants(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<Vec<ServiceGrant>>, ConnectException> {
+    let (service, _) = server_context::session(&headers, &service, EServiceRole::ADMIN)?;
+    Ok(Json(service.grants()))
+}
+
+pub async fn grant(headers: HeaderMap, Path(service): Path<String>, Json(grant): Json<ServiceGrant>) -> Result<Json<Vec<ServiceGrant>>, ConnectException> {
+    let (mut service, session) = server_context::session(&headers, &service, EServiceRole::ADMIN)?;
+    let user = session.user();
+    service.grant(&user, grant)?;
+    Configuration::put_service(service.clone())?;
+    Ok(Json(service.grants()))
+}
+
+pub async fn revoke(headers: HeaderMap, Path(service): Path<String>, Json(grant): Json<ServiceGrant>) -> Result<Json<Vec<ServiceGrant>>, ConnectException> {
+    let (mut service, session) = server_context::session(&headers, &service, EServiceRole::ADMIN)?;
+    let user = session.user();
+    if service.revoke(&user, &grant)?.is_none() {
+        return Err(ConnectException::from_status(ConnectException::NOT_FOUND, String::from("Grant not found.")));
+    }
+    Configuration::put_service(service.clone())?;
+    Ok(Json(service.grants()))
+}
+
+pub async fn status(headers: HeaderMap, Path(service): Path<String>) -> Result<StatusCode, ConnectException> {
+    server_context::instance(&headers, &service).await?.status().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn metadata(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<Vec<TableDataGroup>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.metadata().await?))
+}
+
+pub async fn actions(headers: HeaderMap, Path(service): Path<String>) -> Result<Json<Vec<ActionDefinition>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.actions().await?))
+}
+
+pub async fn action(headers: HeaderMap, Path((service, code)): Path<(String, String)>) -> Result<Json<ActionDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    match service.action(&code).await? {
+        Some(action) => Ok(Json(action)),
+        None => Err(ConnectException::from_status(ConnectException::NOT_FOUND, format!("Action '{}' not found.", code))),
+    }
+}
+
+pub async fn execute_action(headers: HeaderMap, Path(service): Path<String>, Json(action): Json<Action>) -> Result<Json<String>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.execute_action(&action).await?))
+}
+
+pub async fn operations(headers: HeaderMap, Path(service): Path<String>, Query(query): Query<OperationQuery>) -> Result<Json<Vec<OperationData>>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.operations(&query).await?))
+}
+
+pub async fn operations_table(headers: HeaderMap, Path(service): Path<String>, Query(query): Query<OperationQuery>) -> Result<Json<TableDefinition>, ConnectException> {
+    let service = server_context::instance(&headers, &service).await?;
+    Ok(Json(service.operations_table(&query).await?))
+}
\ No newline at end of file
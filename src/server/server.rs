@@ -0,0 +1,82 @@
+use axum::{extract::DefaultBodyLimit, routing::{get, post, put}, Router};
+use tokio::net::TcpListener;
+
+use crate::domain::permission::service_user::ServiceUser;
+
+use super::{handler::{change_handler, collection_handler, data_base_handler, document_handler, file_handler, query_handler, service_handler}, server_context::{self, ServerIdentity}};
+
+const SERVICE: &str = "/api/v1/service/:service";
+const DATA_BASE: &str = "/api/v1/service/:service/data-base/:data_base";
+const FILE_BUCKET: &str = "/api/v1/service/:service/data-base/:data_base/file-bucket/:bucket";
+const COLLECTION: &str = "/api/v1/service/:service/data-base/:data_base/collection/:collection";
+
+pub struct Server {
+}
+
+impl Server {
+
+    pub async fn serve(address: &str, user: ServiceUser, sw_trust_headers: bool) -> std::io::Result<()> {
+        let listener = TcpListener::bind(address).await?;
+        let sw_loopback = listener.local_addr()?.ip().is_loopback();
+        server_context::set_identity(ServerIdentity::new(user, sw_trust_headers, sw_loopback));
+        axum::serve(listener, Server::router()).await
+    }
+
+    pub fn router() -> Router {
+        Router::new()
+            .route("/api/v1/service", get(service_handler::find_all).post(service_handler::insert))
+            .route(SERVICE, get(service_handler::find).delete(service_handler::delete))
+            .route(&format!("{}/read-only", SERVICE), put(service_handler::read_only))
+            .route(&format!("{}/session", SERVICE), post(service_handler::session_open).delete(service_handler::session_close))
+            .route(&format!("{}/grant", SERVICE), get(service_handler::grants).post(service_handler::grant).delete(service_handler::revoke))
+            .route(&format!("{}/status", SERVICE), get(service_handler::status))
+            .route(&format!("{}/metadata", SERVICE), get(service_handler::metadata))
+            .route(&format!("{}/action", SERVICE), get(service_handler::actions).post(service_handler::execute_action))
+            .route(&format!("{}/action/:code", SERVICE), get(service_handler::action))
+            .route(&format!("{}/operation", SERVICE), get(service_handler::operations))
+            .route(&format!("{}/operation/table", SERVICE), get(service_handler::operations_table))
+            .route(&format!("{}/query", SERVICE), get(query_handler::find_all).post(query_handler::insert))
+            .route(&format!("{}/query/:name", SERVICE), get(query_handler::find).delete(query_handler::delete))
+            .route(&format!("{}/query/:name/run", SERVICE), post(query_handler::run))
+            .route(&format!("{}/history", SERVICE), get(query_handler::history))
+            .route(&format!("{}/transaction", SERVICE), post(document_handler::transaction))
+            .route(&format!("{}/schema/collection", SERVICE), get(data_base_handler::collection_schema))
+            .route(&format!("{}/schema/filter", SERVICE), get(data_base_handler::filter_schema))
+            .route(&format!("{}/data-base", SERVICE), get(data_base_handler::find_all).post(data_base_handler::insert))
+            .route(DATA_BASE, get(data_base_handler::exists).delete(data_base_handler::delete))
+            .route(&format!("{}/metadata", DATA_BASE), get(data_base_handler::metadata))
+            .route(&format!("{}/action", DATA_BASE), get(data_base_handler::actions).post(data_base_handler::execute_action))
+            .route(&format!("{}/action/:code", DATA_BASE), get(data_base_handler::action))
+            .route(&format!("{}/profile", DATA_BASE), get(data_base_handler::profile))
+            .route(&format!("{}/shell", DATA_BASE), post(document_handler::shell))
+            .route(&format!("{}/watch", DATA_BASE), get(change_handler::watch_data_base))
+            .route(&format!("{}/file-bucket", DATA_BASE), get(file_handler::buckets))
+            .route(&format!("{}/file-bucket/table", DATA_BASE), get(file_handler::buckets_table))
+            .route(&format!("{}/file", FILE_BUCKET), get(file_handler::find_all).post(file_handler::upload).layer(DefaultBodyLimit::disable()))
+            .route(&format!("{}/file/table", FILE_BUCKET), get(file_handler::table))
+            .route(&format!("{}/file/:id", FILE_BUCKET), get(file_handler::download).delete(file_handler::delete))
+            .route(&format!("{}/file/:id/name", FILE_BUCKET), put(file_handler::rename))
+            .route(&format!("{}/collection", DATA_BASE), get(collection_handler::find_all).post(collection_handler::insert))
+            .route(COLLECTION, get(collection_handler::exists).delete(collection_handler::delete))
+            .route(&format!("{}/name", COLLECTION), put(collection_handler::rename))
+            .route(&format!("{}/metadata", COLLECTION), get(collection_handler::metadata))
+            .route(&format!("{}/information", COLLECTION), get(collection_handler::information))
+            .route(&format!("{}/schema", COLLECTION), get(collection_handler::schema))
+            .route(&format!("{}/action", COLLECTION), get(collection_handler::actions).post(collection_handler::execute_action))
+            .route(&format!("{}/action/:code", COLLECTION), get(collection_handler::action))
+            .route(&format!("{}/validate", COLLECTION), post(collection_handler::validate))
+            .route(&format!("{}/storage", COLLECTION), get(collection_handler::storage))
+            .route(&format!("{}/export", COLLECTION), get(collection_handler::export))
+            .route(&format!("{}/import", COLLECTION), post(collection_handler::import))
+            .route(&format!("{}/query", COLLECTION), post(document_handler::find_query))
+            .route(&format!("{}/explain", COLLECTION), post(document_handler::explain))
+            .route(&format!("{}/aggregate", COLLECTION), post(document_handler::aggregate))
+            .route(&format!("{}/aggregate/table", COLLECTION), post(document_handler::aggregate_table))
+            .route(&format!("{}/where", COLLECTION), post(document_handler::find_where))
+            .route(&format!("{}/watch", COLLECTION), get(change_handler::watch_collection))
+            .route(&format!("{}/find-all", COLLECTION), post(document_handler::find_all))
+            .route(&format!("{}/find", COLLECTION), post(document_handler::find))
+            .route(&format!("{}/document", COLLECTION), post(document_handler::insert).put(document_handler::update).delete(document_handler::delete))
+    }
+
+}
\ No newline at end of file
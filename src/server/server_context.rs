@@ -0,0 +1,127 @@
+use std::sync::Mutex;
+
+use axum::http::{header::AUTHORIZATION, HeaderMap};
+use lazy_static::lazy_static;
+
+use crate::{
+    commons::{configuration::configuration::Configuration, exception::connect_exception::ConnectException},
+    domain::permission::{e_service_role::EServiceRole, service_user::ServiceUser},
+    infrastructure::{db_service::DBService, db_session::DBSession, repository::i_db_repository::IDBRepository},
+    service::service::Service,
+};
+
+pub const HEADER_USER: &str = "x-service-user";
+pub const HEADER_GROUPS: &str = "x-service-groups";
+
+const BEARER: &str = "Bearer ";
+
+lazy_static! {
+    static ref IDENTITY: Mutex<Option<ServerIdentity>> = Mutex::new(None);
+}
+
+#[derive(Clone)]
+pub struct ServerIdentity {
+    user: ServiceUser,
+    sw_trust_headers: bool,
+    sw_loopback: bool
+}
+
+impl ServerIdentity {
+
+    pub fn new(user: ServiceUser, sw_trust_headers: bool, sw_loopback: bool) -> Self {
+        Self {
+            user, sw_trust_headers, sw_loopback
+        }
+    }
+
+}
+
+pub fn set_identity(identity: ServerIdentity) {
+    *IDENTITY.lock().expect("Could not lock mutex") = Some(identity);
+}
+
+fn identity() -> Result<ServerIdentity, ConnectException> {
+    match IDENTITY.lock().expect("Could not lock mutex").clone() {
+        Some(identity) => Ok(identity),
+        None => Err(ConnectException::from_status(ConnectException::INTERNAL_ERROR, String::from("Server identity is not configured."))),
+    }
+}
+
+/// The user a request acts as: the server user, or the identity headers when an authenticating proxy is trusted.
+pub fn caller(headers: &HeaderMap) -> Result<ServiceUser, ConnectException> {
+    let identity = identity()?;
+    if !identity.sw_trust_headers {
+        if header(headers, HEADER_USER).is_some() || header(headers, HEADER_GROUPS).is_some() {
+            return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, String::from("Identity headers are not trusted by this server.")));
+        }
+        return Ok(identity.user);
+    }
+
+    let name = match header(headers, HEADER_USER) {
+        Some(name) if !name.is_empty() => name,
+        _ => return Err(ConnectException::from_status(ConnectException::UNAUTHORIZED, format!("Header '{}' is required.", HEADER_USER))),
+    };
+
+    let groups = header(headers, HEADER_GROUPS)
+        .map(|g| g.split(',')
+            .map(|s| String::from(s.trim()))
+            .filter(|s| !s.is_empty())
+            .collect())
+        .unwrap_or_default();
+
+    Ok(ServiceUser::new(name, groups))
+}
+
+/// Unprotected services have no credential to check, so sessions are only opened for loopback or proxy-authenticated callers.
+pub fn check_session_open(service: &DBService) -> Result<(), ConnectException> {
+    let identity = identity()?;
+    if service.is_protected() || identity.sw_trust_headers || identity.sw_loopback {
+        return Ok(());
+    }
+
+    Err(ConnectException::from_status(ConnectException::FORBIDDEN, format!("Service '{}' is not protected; sessions are only opened on a loopback address or behind a trusted proxy.", service.name())))
+}
+
+pub fn token(headers: &HeaderMap) -> Option<String> {
+    header(headers, AUTHORIZATION.as_str())
+        .and_then(|h| h.strip_prefix(BEARER).map(String::from))
+}
+
+pub fn find_service(name: &str, user: &ServiceUser, required: EServiceRole) -> Result<DBService, ConnectException> {
+    let service = match Configuration::find_service_unchecked(name)? {
+        Some(service) => service,
+        None => return Err(ConnectException::from_status(ConnectException::NOT_FOUND, format!("Service '{}' not found.", name))),
+    };
+
+    service.check_role(user, required)?;
+
+    Ok(service)
+}
+
+pub fn session(headers: &HeaderMap, name: &str, required: EServiceRole) -> Result<(DBService, DBSession), ConnectException> {
+    let token = match token(headers) {
+        Some(token) => token,
+        None => return Err(ConnectException::from_status(ConnectException::UNAUTHORIZED, String::from("A session token is required."))),
+    };
+
+    let service = match Configuration::find_service_unchecked(name)? {
+        Some(service) => service,
+        None => return Err(ConnectException::from_status(ConnectException::NOT_FOUND, format!("Service '{}' not found.", name))),
+    };
+
+    let session = service.is_authorized_session(&token)?;
+    service.check_role(&session.user(), required)?;
+
+    Ok((service, session))
+}
+
+pub async fn instance(headers: &HeaderMap, name: &str) -> Result<Service<impl IDBRepository>, ConnectException> {
+    let (service, session) = session(headers, name, EServiceRole::VIEWER)?;
+    service.instance_as(&session.user()).await
+}
+
+fn header(headers: &HeaderMap, key: &str) -> Option<String> {
+    headers.get(key)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+}
\ No newline at end of file
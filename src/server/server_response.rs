@@ -0,0 +1,15 @@
+use axum::{http::StatusCode, response::{IntoResponse, Response}, Json};
+use serde_json::json;
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+impl IntoResponse for ConnectException {
+
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = json!({ "status": status.as_u16(), "message": self.message() });
+        (status, Json(body)).into_response()
+    }
+
+}
\ No newline at end of file
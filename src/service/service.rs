@@ -1,33 +1,124 @@
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use futures_util::{io::{AsyncRead, AsyncWrite}, stream::BoxStream};
+
 use crate::{
-    commons::exception::connect_exception::ConnectException,
+    commons::{exception::connect_exception::ConnectException, utils},
     domain::{
+        aggregate::aggregate_query::AggregateQuery,
         action::{definition::action_definition::ActionDefinition, generate::action::Action},
+        change::{change_event::ChangeEvent, watch_query::WatchQuery},
         collection::{
             collection_data::CollectionData, collection_definition::CollectionDefinition,
             generate_collection_query::GenerateCollectionQuery,
         },
         data_base::generate_database_query::GenerateDatabaseQuery,
         document::{document_data::DocumentData, document_schema::DocumentSchema},
+        file::{file_bucket::FileBucket, file_data::FileData, file_query::FileQuery},
         filter::{
             collection_query::CollectionQuery, data_base_query::DataBaseQuery,
             definition::filter_definition::FilterDefinition, document_query::DocumentQuery,
+            filter_element::FilterElement,
         },
+        operation::{operation_data::OperationData, operation_query::OperationQuery},
+        permission::e_service_role::EServiceRole,
+        query::{query_explain::QueryExplain, query_record::QueryRecord},
         table::{
             definition::table_definition::TableDefinition, group::table_data_group::TableDataGroup,
         },
+        transaction::document_transaction::DocumentTransaction,
     },
-    infrastructure::repository::i_db_repository::IDBRepository,
+    infrastructure::{db_query_history::DBQueryHistory, repository::{i_db_repository::IDBRepository, i_db_transaction::IDBTransaction}},
 };
 
 #[derive(Clone)]
 pub struct Service<T: IDBRepository> {
     repository: T,
+    role: EServiceRole,
+    read_only: bool,
+    history: Option<(String, String)>,
 }
 
 impl <T: IDBRepository> Service<T> {
 
     pub fn from(repository: T) -> Service<T> {
-        Service { repository }
+        Service::from_role(repository, EServiceRole::VIEWER)
+    }
+
+    pub fn from_role(repository: T, role: EServiceRole) -> Service<T> {
+        let read_only = repository.is_read_only();
+        Service { repository, role, read_only, history: None }
+    }
+
+    pub fn as_read_only(mut self) -> Service<T> {
+        self.read_only = true;
+        self
+    }
+
+    pub fn as_recorded(mut self, service: String, user: String) -> Service<T> {
+        self.history = Some((service, user));
+        self
+    }
+
+    pub fn role(&self) -> EServiceRole {
+        self.role
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn check_role(&self, required: EServiceRole) -> Result<(), ConnectException> {
+        if self.read_only && required > EServiceRole::VIEWER {
+            return Err(ConnectException::from_status(ConnectException::FORBIDDEN, String::from("Permission denied: service is in read-only mode.")));
+        }
+
+        self.check_grant(required)
+    }
+
+    fn check_grant(&self, required: EServiceRole) -> Result<(), ConnectException> {
+        if !self.role.allows(required) {
+            let message = format!("Permission denied: '{}' role is required for this operation.", required.to_string());
+            return Err(ConnectException::from_status(ConnectException::FORBIDDEN, message));
+        }
+        Ok(())
+    }
+
+    fn check_parameters(query: &DocumentQuery) -> Result<(), ConnectException> {
+        let parameters = query.parameters();
+        if !parameters.is_empty() {
+            let message = format!("Query has unbound parameters: {}.", parameters.join(", "));
+            return Err(ConnectException::from_status(ConnectException::BAD_REQUEST, message));
+        }
+        Ok(())
+    }
+
+    fn check_stages(&self, filter: Option<FilterElement>) -> Result<(), ConnectException> {
+        if filter.is_some_and(|f| f.has_write_stage()) {
+            return self.check_role(EServiceRole::EDITOR);
+        }
+        Ok(())
+    }
+
+    fn record<R>(&self, query: &DocumentQuery, start: Instant, result: &Result<R, ConnectException>) {
+        let (service, user) = match &self.history {
+            Some(history) => history.clone(),
+            None => return,
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Cannot read actual date.")
+            .as_millis();
+
+        let latency = start.elapsed().as_millis();
+
+        let record = match result {
+            Ok(_) => QueryRecord::success(service, user, timestamp, latency, query.clone()),
+            Err(error) => QueryRecord::failure(service, user, timestamp, latency, query.clone(), error.message()),
+        };
+
+        DBQueryHistory::push(record);
     }
 
     pub async fn status(&self) -> Result<(), ConnectException> {
@@ -38,18 +129,65 @@ impl <T: IDBRepository> Service<T> {
         return self.repository.metadata().await;
     }
 
+    pub async fn actions(&self) -> Result<Vec<ActionDefinition>, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
+        self.repository.actions().await
+    }
+
+    pub async fn action(&self, code: &str) -> Result<Option<ActionDefinition>, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
+        self.repository.action(code).await
+    }
+
+    pub async fn execute_action(&self, action: &Action) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::ADMIN)?;
+        self.repository.execute_action(action).await
+    }
+
+    pub async fn operations(&self, query: &OperationQuery) -> Result<Vec<OperationData>, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
+        self.repository.operations(query).await
+    }
+
+    pub async fn operations_table(&self, query: &OperationQuery) -> Result<TableDefinition, ConnectException> {
+        let operations = self.operations(query).await?;
+        Ok(utils::operations_as_table(String::from("Operations"), &operations))
+    }
+
     pub async fn data_base_exists(&self, query: &DataBaseQuery) -> Result<bool, ConnectException> {
         return self.repository.data_base_exists(query).await;
     }
 
     pub async fn data_base_create(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::ADMIN)?;
         return self.repository.data_base_create(query).await;
     }
 
     pub async fn data_base_drop(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::ADMIN)?;
         return self.repository.data_base_drop(query).await;
     }
 
+    pub async fn data_base_actions(&self, query: &DataBaseQuery) -> Result<Vec<ActionDefinition>, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
+        self.repository.data_base_actions(query).await
+    }
+
+    pub async fn data_base_action(&self, query: &DataBaseQuery, code: &str) -> Result<Option<ActionDefinition>, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
+        self.repository.data_base_action(query, code).await
+    }
+
+    pub async fn data_base_execute_action(&self, query: &DataBaseQuery, action: &Action) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::ADMIN)?;
+        self.repository.data_base_execute_action(query, action).await
+    }
+
+    pub async fn data_base_profile(&self, query: &DataBaseQuery) -> Result<TableDefinition, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
+        self.repository.data_base_profile(query).await
+    }
+
     pub async fn data_base_find_all(&self) -> Result<Vec<String>, ConnectException> {
         return self.repository.data_base_find_all().await;
     }
@@ -71,30 +209,46 @@ impl <T: IDBRepository> Service<T> {
     }
 
     pub async fn collection_actions(&self, query: &CollectionQuery) -> Result<Vec<ActionDefinition>, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
         return self.repository.collection_actions(query).await;
     }
 
     pub async fn collection_action(&self, query: &CollectionQuery, code: &String) -> Result<Option<ActionDefinition>, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
         return self.repository.collection_action(query, code).await;
     }
 
     pub async fn collection_execute_action(&self, query: &CollectionQuery, action: &Action) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::ADMIN)?;
         return self.repository.collection_execute_action(query, action).await;
     }
 
+    pub async fn collection_validate(&self, query: &CollectionQuery, sw_full: bool) -> Result<TableDefinition, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
+        self.repository.collection_validate(query, sw_full).await
+    }
+
+    pub async fn collection_storage(&self, query: &CollectionQuery) -> Result<TableDefinition, ConnectException> {
+        self.check_grant(EServiceRole::ADMIN)?;
+        self.repository.collection_storage(query).await
+    }
+
     pub async fn collection_exists(&self, query: &CollectionQuery) -> Result<bool, ConnectException> {
         return self.repository.collection_exists(query).await;
     }
 
     pub async fn collection_create(&self, query: &GenerateCollectionQuery) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::ADMIN)?;
         return self.repository.collection_create(query).await;
     }
 
     pub async fn collection_drop(&self, query: &GenerateCollectionQuery) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::ADMIN)?;
         return self.repository.collection_drop(query).await;
     }
 
     pub async fn collection_rename(&self, query: &CollectionQuery, name: &str) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::ADMIN)?;
         return self.repository.collection_rename(query, name).await;
     }
 
@@ -103,6 +257,7 @@ impl <T: IDBRepository> Service<T> {
     }
 
     pub async fn collection_import(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::EDITOR)?;
         return self.repository.collection_import(query, documents).await;
     }
 
@@ -110,20 +265,98 @@ impl <T: IDBRepository> Service<T> {
         return self.repository.collection_find_all(query).await;
     }
 
+    pub async fn file_buckets(&self, query: &DataBaseQuery) -> Result<Vec<FileBucket>, ConnectException> {
+        self.repository.file_buckets(query).await
+    }
+
+    pub async fn file_buckets_table(&self, query: &DataBaseQuery) -> Result<TableDefinition, ConnectException> {
+        let buckets = self.repository.file_buckets(query).await?;
+        Ok(utils::buckets_as_table(query.data_base(), &buckets))
+    }
+
+    pub async fn file_find_all(&self, query: &FileQuery) -> Result<Vec<FileData>, ConnectException> {
+        self.repository.file_find_all(query).await
+    }
+
+    pub async fn file_table(&self, query: &FileQuery) -> Result<TableDefinition, ConnectException> {
+        let files = self.repository.file_find_all(query).await?;
+        Ok(utils::files_as_table(query.bucket(), &files))
+    }
+
+    pub async fn file_upload(&self, query: &FileQuery, filename: &str, metadata: Option<&str>, source: &mut (dyn AsyncRead + Unpin + Send)) -> Result<FileData, ConnectException> {
+        self.check_role(EServiceRole::EDITOR)?;
+        self.repository.file_upload(query, filename, metadata, source).await
+    }
+
+    pub async fn file_download(&self, query: &FileQuery, id: &str, destination: &mut (dyn AsyncWrite + Unpin + Send)) -> Result<(), ConnectException> {
+        self.repository.file_download(query, id, destination).await
+    }
+
+    pub async fn file_delete(&self, query: &FileQuery, id: &str) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::EDITOR)?;
+        self.repository.file_delete(query, id).await
+    }
+
+    pub async fn file_rename(&self, query: &FileQuery, id: &str, filename: &str) -> Result<String, ConnectException> {
+        self.check_role(EServiceRole::EDITOR)?;
+        self.repository.file_rename(query, id, filename).await
+    }
+
     pub async fn filter_schema(&self) -> Result<FilterDefinition, ConnectException> {
         return self.repository.filter_schema().await;
     }
 
+    pub async fn parse_query(&self, data_base: &str, query: &str) -> Result<DocumentQuery, ConnectException> {
+        return self.repository.parse_query(data_base, query).await;
+    }
+
     pub async fn find_query(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
-        return self.repository.find_query(query).await;
+        Self::check_parameters(query)?;
+        self.check_stages(query.filter())?;
+        let start = Instant::now();
+        let result = self.repository.find_query(query).await;
+        self.record(query, start, &result);
+        result
     }
 
     pub async fn find_all(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
-        return self.repository.find_all(query).await;
+        Self::check_parameters(query)?;
+        self.check_stages(query.filter())?;
+        let start = Instant::now();
+        let result = self.repository.find_all(query).await;
+        self.record(query, start, &result);
+        result
     }
     
     pub async fn find(&self, query: &DocumentQuery) -> Result<Option<DocumentData>, ConnectException> {
-        return self.repository.find(query).await;
+        Self::check_parameters(query)?;
+        self.check_stages(query.filter())?;
+        let start = Instant::now();
+        let result = self.repository.find(query).await;
+        self.record(query, start, &result);
+        result
+    }
+
+    pub async fn explain(&self, query: &DocumentQuery) -> Result<QueryExplain, ConnectException> {
+        Self::check_parameters(query)?;
+        self.check_stages(query.filter())?;
+        self.repository.explain(query).await
+    }
+
+    pub async fn aggregate(&self, query: &AggregateQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        if query.pipeline().has_write_stage() {
+            self.check_role(EServiceRole::EDITOR)?;
+        }
+        self.repository.aggregate(query).await
+    }
+
+    pub async fn aggregate_table(&self, query: &AggregateQuery) -> Result<TableDefinition, ConnectException> {
+        let documents = self.aggregate(query).await?;
+        Ok(utils::documents_as_table(query.collection(), &documents))
+    }
+
+    pub async fn watch(&self, query: &WatchQuery) -> Result<BoxStream<'static, Result<ChangeEvent, ConnectException>>, ConnectException> {
+        self.repository.watch(query).await
     }
 
     pub async fn schema(&self, query: &CollectionQuery) ->  Result<DocumentSchema, ConnectException> {
@@ -131,15 +364,47 @@ impl <T: IDBRepository> Service<T> {
     }
 
     pub async fn insert(&self, query: &CollectionQuery, value: &str) -> Result<DocumentData, ConnectException> {
+        self.check_role(EServiceRole::EDITOR)?;
         return self.repository.insert(query, &value).await;
     }
 
     pub async fn update(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        self.check_role(EServiceRole::EDITOR)?;
         return self.repository.update(query, value).await;
     }
 
     pub async fn delete(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>,ConnectException> {
+        self.check_role(EServiceRole::EDITOR)?;
         return self.repository.delete(query).await;
     }
 
+    pub async fn begin(&self) -> Result<Box<dyn IDBTransaction>, ConnectException> {
+        self.check_role(EServiceRole::EDITOR)?;
+        self.repository.begin().await
+    }
+
+    pub async fn transaction(&self, transaction: &DocumentTransaction) -> Result<Vec<Vec<DocumentData>>, ConnectException> {
+        if transaction.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut session = self.begin().await?;
+
+        let mut results = Vec::new();
+        for (index, operation) in transaction.operations().iter().enumerate() {
+            match session.execute(operation).await {
+                Ok(documents) => results.push(documents),
+                Err(error) => {
+                    let _ = session.abort().await;
+                    let message = format!("Transaction aborted at operation {} ({}): {}", index, operation.code(), error.message());
+                    return Err(ConnectException::from_status(error.status(), message));
+                },
+            }
+        }
+
+        session.commit().await?;
+
+        Ok(results)
+    }
+
 }
\ No newline at end of file
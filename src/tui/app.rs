@@ -0,0 +1,418 @@
+use std::{collections::HashMap, io};
+
+use rust_db_manager_core::{
+    commons::{configuration::configuration::Configuration, exception::connect_exception::ConnectException, utils::document_keys_to_filter_element},
+    domain::{
+        action::{
+            definition::action_definition::ActionDefinition,
+            generate::{action::Action, action_form::ActionForm, form_field::FormField},
+        },
+        collection::generate_collection_query::GenerateCollectionQuery,
+        connection_data::ConnectionData,
+        data_base::generate_database_query::GenerateDatabaseQuery,
+        document::document_data::DocumentData,
+        filter::{collection_query::CollectionQuery, data_base_query::DataBaseQuery, document_query::DocumentQuery},
+        permission::{e_service_role::EServiceRole, service_user::ServiceUser},
+        table::group::table_data_group::TableDataGroup,
+    },
+    infrastructure::{db_service::DBService, repository::{e_db_repository::EDBRepository, i_db_repository::IDBRepository}},
+    service::service::Service,
+};
+
+use super::{
+    terminal::Terminal,
+    view::{groups_as_lines, json_as_lines, tables_as_lines},
+    widget::{confirm, input, list, select, text, Choice},
+};
+
+const PAGE_SIZE: usize = 25;
+
+pub struct App {
+    terminal: Terminal,
+    user: ServiceUser,
+    sessions: HashMap<String, String>
+}
+
+impl App {
+
+    pub fn open() -> io::Result<App> {
+        Ok(App {
+            terminal: Terminal::open()?,
+            user: crate::local_user(),
+            sessions: HashMap::new()
+        })
+    }
+
+    pub async fn run(&mut self) -> io::Result<()> {
+        let mut cursor = 0;
+        let mut status = None;
+        loop {
+            let services = match Configuration::find_services_as(&self.user) {
+                Ok(services) => services,
+                Err(error) => {
+                    status = Some(error.message());
+                    Vec::new()
+                },
+            };
+
+            let items: Vec<String> = services.iter()
+                .map(|s| format!("{}  ({})", s.name(), s.category().to_string()))
+                .collect();
+
+            let choice = list(&mut self.terminal, "rust-db-manager · Services", &items,
+                "Enter open · n new · d remove · q quit", &['n', 'd', 'q'], &mut status, &mut cursor)?;
+
+            match choice {
+                Choice::Back | Choice::Key('q', _) => return Ok(()),
+                Choice::Key('n', _) => status = self.register()?,
+                Choice::Key('d', Some(index)) => status = self.remove(&services[index].name())?,
+                Choice::Select(index) => status = self.open_service(&services[index].name()).await?,
+                _ => {},
+            }
+        }
+    }
+
+    fn register(&mut self) -> io::Result<Option<String>> {
+        let title = "Register service";
+
+        let categories = EDBRepository::items();
+        let labels: Vec<String> = categories.iter().map(|c| c.to_string()).collect();
+        let category = match select(&mut self.terminal, title, &labels)? {
+            Some(index) => categories[index].clone(),
+            None => return Ok(None),
+        };
+
+        let name = match input(&mut self.terminal, title, "Service name:", "", false)? {
+            Some(name) if !name.trim().is_empty() => name.trim().to_string(),
+            _ => return Ok(None),
+        };
+
+        let connection = match input(&mut self.terminal, title, "Connection URI:", "", false)? {
+            Some(connection) => connection,
+            None => return Ok(None),
+        };
+
+        let connection_data = match ConnectionData::parse(category, connection) {
+            Ok(connection_data) => connection_data,
+            Err(error) => return Ok(Some(error.message())),
+        };
+
+        let protected = confirm(&mut self.terminal, title, "Protect this service with a password? (y/n)")?;
+        let mut password = String::new();
+        if protected {
+            password = match input(&mut self.terminal, title, "Service password:", "", true)? {
+                Some(password) => password,
+                None => return Ok(None),
+            };
+        }
+
+        let read_only = confirm(&mut self.terminal, title, "Open this service in read-only mode? (y/n)")?;
+
+        let mut service = match DBService::new(name, self.user.name(), protected, password, connection_data) {
+            Ok(service) => service,
+            Err(error) => return Ok(Some(error.message())),
+        };
+        service.set_read_only(read_only);
+
+        match Configuration::push_service(&service) {
+            Ok(service) => Ok(Some(format!("Service '{}' registered.", service.name()))),
+            Err(error) => Ok(Some(error.message())),
+        }
+    }
+
+    fn remove(&mut self, name: &str) -> io::Result<Option<String>> {
+        let service = match Configuration::find_service_as(name, &self.user, EServiceRole::ADMIN) {
+            Ok(Some(service)) => service,
+            Ok(None) => return Ok(Some(String::from("Service not found."))),
+            Err(error) => return Ok(Some(error.message())),
+        };
+
+        if !confirm(&mut self.terminal, "Remove service", &format!("Remove service '{}'? (y/n)", name))? {
+            return Ok(None);
+        }
+
+        self.sessions.remove(name);
+
+        match Configuration::remove_service(service) {
+            Ok(_) => Ok(Some(format!("Service '{}' removed.", name))),
+            Err(error) => Ok(Some(error.message())),
+        }
+    }
+
+    async fn open_service(&mut self, name: &str) -> io::Result<Option<String>> {
+        let service = match Configuration::find_service_as(name, &self.user, EServiceRole::VIEWER) {
+            Ok(Some(service)) => service,
+            Ok(None) => return Ok(Some(String::from("Service not found."))),
+            Err(error) => return Ok(Some(error.message())),
+        };
+
+        if service.is_protected() {
+            let valid = self.sessions.get(name)
+                .is_some_and(|token| service.is_authorized_session(token).is_ok());
+            if !valid {
+                let password = match input(&mut self.terminal, &format!("Authorize · {}", name), "Service password:", "", true)? {
+                    Some(password) => password,
+                    None => return Ok(None),
+                };
+                match service.authorize(&self.user, password) {
+                    Ok(session) => self.sessions.insert(service.name(), session.token()),
+                    Err(error) => return Ok(Some(error.message())),
+                };
+            }
+        }
+
+        match service.instance_as(&self.user).await {
+            Ok(instance) => self.browse(&service, instance).await,
+            Err(error) => Ok(Some(error.message())),
+        }
+    }
+
+    async fn browse<T: IDBRepository>(&mut self, definition: &DBService, service: Service<T>) -> io::Result<Option<String>> {
+        let mut title = format!("{} · {}", definition.name(), definition.connection_data());
+        if service.is_read_only() {
+            title = format!("{} [read-only]", title);
+        }
+
+        let mut cursor = 0;
+        let mut status = None;
+        loop {
+            let data_bases = match service.data_base_find_all().await {
+                Ok(data_bases) => data_bases,
+                Err(error) => return Ok(Some(error.message())),
+            };
+
+            let choice = list(&mut self.terminal, &title, &data_bases,
+                "Enter open · m metadata · c create · x drop · Esc back", &['m', 'c', 'x'], &mut status, &mut cursor)?;
+
+            match choice {
+                Choice::Back => return Ok(None),
+                Choice::Select(index) => status = self.data_base(&service, &data_bases[index]).await?,
+                Choice::Key('m', _) => status = self.show_groups("Server metadata", service.metadata().await)?,
+                Choice::Key('c', _) => {
+                    if let Some(name) = input(&mut self.terminal, &title, "Data base name:", "", false)? {
+                        let query = GenerateDatabaseQuery::new(name);
+                        status = Some(report(service.data_base_create(&query).await, |n| format!("Data base '{}' created.", n)));
+                    }
+                },
+                Choice::Key('x', Some(index)) => {
+                    let name = data_bases[index].clone();
+                    if confirm(&mut self.terminal, &title, &format!("Drop data base '{}'? (y/n)", name))? {
+                        let query = GenerateDatabaseQuery::new(name);
+                        status = Some(report(service.data_base_drop(&query).await, |n| format!("Data base '{}' dropped.", n)));
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    async fn data_base<T: IDBRepository>(&mut self, service: &Service<T>, data_base: &str) -> io::Result<Option<String>> {
+        let title = format!("Data base · {}", data_base);
+        let query = DataBaseQuery::from(String::from(data_base));
+
+        let mut cursor = 0;
+        let mut status = None;
+        loop {
+            let collections = match service.collection_find_all(&query).await {
+                Ok(collections) => collections,
+                Err(error) => return Ok(Some(error.message())),
+            };
+
+            let choice = list(&mut self.terminal, &title, &collections,
+                "Enter open · m metadata · n new · r rename · x drop · Esc back", &['m', 'n', 'r', 'x'], &mut status, &mut cursor)?;
+
+            match choice {
+                Choice::Back => return Ok(None),
+                Choice::Select(index) => status = self.documents(service, data_base, &collections[index]).await?,
+                Choice::Key('m', _) => status = self.show_groups(&title, service.data_base_metadata(&query).await)?,
+                Choice::Key('n', _) => {
+                    if let Some(name) = input(&mut self.terminal, &title, "Collection name:", "", false)? {
+                        let defaults = match service.collection_accept_schema().await {
+                            Ok(schema) => schema.defaults(),
+                            Err(_) => Vec::new(),
+                        };
+                        let generate = GenerateCollectionQuery::new(String::from(data_base), name, defaults);
+                        status = Some(report(service.collection_create(&generate).await, |n| format!("Collection '{}' created.", n)));
+                    }
+                },
+                Choice::Key('r', Some(index)) => {
+                    let current = collections[index].clone();
+                    if let Some(name) = input(&mut self.terminal, &title, "New collection name:", &current, false)? {
+                        let collection = CollectionQuery::from(String::from(data_base), current);
+                        status = Some(report(service.collection_rename(&collection, &name).await, |n| format!("Collection renamed to '{}'.", n)));
+                    }
+                },
+                Choice::Key('x', Some(index)) => {
+                    let name = collections[index].clone();
+                    if confirm(&mut self.terminal, &title, &format!("Drop collection '{}'? (y/n)", name))? {
+                        let generate = GenerateCollectionQuery::from_collection(String::from(data_base), name);
+                        status = Some(report(service.collection_drop(&generate).await, |n| format!("Collection '{}' dropped.", n)));
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    async fn documents<T: IDBRepository>(&mut self, service: &Service<T>, data_base: &str, collection: &str) -> io::Result<Option<String>> {
+        let collection_query = CollectionQuery::from(String::from(data_base), String::from(collection));
+
+        let mut page = 0;
+        let mut cursor = 0;
+        let mut status = None;
+        loop {
+            let query = DocumentQuery::from(String::from(data_base), String::from(collection), Some(page * PAGE_SIZE), Some(PAGE_SIZE), None);
+            let documents = match service.find_all(&query).await {
+                Ok(data) => data.documents(),
+                Err(error) => return Ok(Some(error.message())),
+            };
+
+            let title = format!("{}.{} · page {}", data_base, collection, page + 1);
+            let items: Vec<String> = documents.iter().map(|d| d.document()).collect();
+
+            let choice = list(&mut self.terminal, &title, &items,
+                "Enter view · n/p page · + insert · u update · d delete · m metadata · i info · a actions · Esc back",
+                &['n', 'p', '+', 'u', 'd', 'm', 'i', 'a'], &mut status, &mut cursor)?;
+
+            match choice {
+                Choice::Back => return Ok(None),
+                Choice::Select(index) => text(&mut self.terminal, &title, &json_as_lines(&documents[index].document()))?,
+                Choice::Key('n', _) if documents.len() == PAGE_SIZE => {
+                    page += 1;
+                    cursor = 0;
+                },
+                Choice::Key('p', _) => {
+                    page = page.saturating_sub(1);
+                    cursor = 0;
+                },
+                Choice::Key('+', _) => {
+                    if let Some(value) = input(&mut self.terminal, &title, "Document JSON:", "{}", false)? {
+                        status = Some(report(service.insert(&collection_query, &value).await, |_| String::from("Document inserted.")));
+                    }
+                },
+                Choice::Key('u', Some(index)) => {
+                    let document = &documents[index];
+                    if let Some(value) = input(&mut self.terminal, &title, "Document JSON:", &document.document(), false)? {
+                        let query = Self::document_query(document);
+                        status = Some(report(service.update(&query, &value).await, |d| format!("{} document(s) updated.", d.len())));
+                    }
+                },
+                Choice::Key('d', Some(index)) if confirm(&mut self.terminal, &title, "Delete the selected document? (y/n)")? => {
+                    let query = Self::document_query(&documents[index]);
+                    status = Some(report(service.delete(&query).await, |d| format!("{} document(s) deleted.", d.len())));
+                },
+                Choice::Key('m', _) => status = self.show_groups(&title, service.collection_metadata(&collection_query).await)?,
+                Choice::Key('i', _) => {
+                    match service.collection_information(&collection_query).await {
+                        Ok(tables) => text(&mut self.terminal, &title, &tables_as_lines(&tables))?,
+                        Err(error) => status = Some(error.message()),
+                    }
+                },
+                Choice::Key('a', _) => status = self.actions(service, &collection_query).await?,
+                _ => {},
+            }
+        }
+    }
+
+    async fn actions<T: IDBRepository>(&mut self, service: &Service<T>, query: &CollectionQuery) -> io::Result<Option<String>> {
+        let title = format!("{}.{} · actions", query.data_base(), query.collection());
+
+        let definitions = match service.collection_actions(query).await {
+            Ok(definitions) => definitions,
+            Err(error) => return Ok(Some(error.message())),
+        };
+
+        let labels: Vec<String> = definitions.iter().map(|d| d.title()).collect();
+        let definition = match select(&mut self.terminal, &title, &labels)? {
+            Some(index) => definitions[index].clone(),
+            None => return Ok(None),
+        };
+
+        if let Some(data) = definition.data() {
+            text(&mut self.terminal, &definition.title(), &tables_as_lines(&data))?;
+        }
+
+        let action = match self.fill_action(&definition)? {
+            Some(action) => action,
+            None => return Ok(None),
+        };
+
+        Ok(Some(report(service.collection_execute_action(query, &action).await, |message| message)))
+    }
+
+    fn fill_action(&mut self, definition: &ActionDefinition) -> io::Result<Option<Action>> {
+        let collection = match definition.form() {
+            Some(collection) => collection,
+            None if definition.data().is_some() => return Ok(None),
+            None => {
+                let question = format!("Run '{}'? (y/n)", definition.title());
+                return match confirm(&mut self.terminal, &definition.title(), &question)? {
+                    true => Ok(Some(Action::new(definition.action(), Vec::new()))),
+                    false => Ok(None),
+                };
+            },
+        };
+
+        let title = definition.title();
+
+        let mut forms = Vec::new();
+        for form in collection.forms() {
+            let mut fields = form.fields();
+            fields.sort_by_key(|f| f.order());
+
+            let mut rows = Vec::new();
+            loop {
+                let mut row = Vec::new();
+                for field in &fields {
+                    let value = match field.values().is_empty() {
+                        true => input(&mut self.terminal, &title, &format!("{}:", field.name()), "", false)?,
+                        false => {
+                            let values = field.values();
+                            let labels: Vec<String> = values.iter().map(|v| v.key()).collect();
+                            select(&mut self.terminal, &format!("{} · {}", title, field.name()), &labels)?
+                                .map(|index| values[index].value())
+                        },
+                    };
+
+                    match value {
+                        Some(value) if !value.is_empty() => row.push(FormField::new(field.code(), value)),
+                        Some(_) => {},
+                        None => return Ok(None),
+                    }
+                }
+                rows.push(row);
+
+                if !form.is_vector() || !confirm(&mut self.terminal, &title, "Add another entry? (y/n)")? {
+                    break;
+                }
+            }
+
+            forms.push(ActionForm::new(form.code(), rows));
+        }
+
+        Ok(Some(Action::new(definition.action(), forms)))
+    }
+
+    fn show_groups(&mut self, title: &str, result: Result<Vec<TableDataGroup>, ConnectException>) -> io::Result<Option<String>> {
+        match result {
+            Ok(groups) => {
+                text(&mut self.terminal, title, &groups_as_lines(&groups))?;
+                Ok(None)
+            },
+            Err(error) => Ok(Some(error.message())),
+        }
+    }
+
+    fn document_query(document: &DocumentData) -> DocumentQuery {
+        let filter = document_keys_to_filter_element(document.keys());
+        DocumentQuery::from_filter(document.data_base(), document.collection(), filter)
+    }
+
+}
+
+fn report<V>(result: Result<V, ConnectException>, success: impl FnOnce(V) -> String) -> String {
+    match result {
+        Ok(value) => success(value),
+        Err(error) => error.message(),
+    }
+}
\ No newline at end of file
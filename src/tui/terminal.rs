@@ -0,0 +1,108 @@
+use std::io::{self, stdout, Stdout, Write};
+
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{read, Event, KeyEvent, KeyEventKind},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+pub struct Terminal {
+    out: Stdout
+}
+
+impl Terminal {
+
+    pub fn open() -> io::Result<Terminal> {
+        let mut out = stdout();
+        enable_raw_mode()?;
+        execute!(out, EnterAlternateScreen, Hide)?;
+        Ok(Terminal { out })
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        let (width, height) = terminal::size().unwrap_or((80, 24));
+        (width as usize, height as usize)
+    }
+
+    pub fn body_height(&self) -> usize {
+        self.size().1.saturating_sub(5).max(1)
+    }
+
+    pub fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        let (width, height) = self.size();
+
+        queue!(self.out, Clear(ClearType::All), MoveTo(0, 0))?;
+        queue!(self.out, SetAttribute(Attribute::Bold), Print(fit(&frame.title, width)), SetAttribute(Attribute::Reset))?;
+        queue!(self.out, MoveTo(0, 1), Print("─".repeat(width)))?;
+
+        let body = height.saturating_sub(5);
+        for (index, line) in frame.lines.iter().take(body).enumerate() {
+            queue!(self.out, MoveTo(0, (index + 2) as u16))?;
+            if frame.selected == Some(index) {
+                queue!(self.out, SetAttribute(Attribute::Reverse), Print(fit(line, width)), SetAttribute(Attribute::Reset))?;
+            } else {
+                queue!(self.out, Print(fit(line, width)))?;
+            }
+        }
+
+        let footer = height.saturating_sub(3) as u16;
+        queue!(self.out, MoveTo(0, footer), Print("─".repeat(width)))?;
+        if let Some(status) = &frame.status {
+            queue!(self.out, MoveTo(0, footer + 1), SetAttribute(Attribute::Bold), Print(fit(status, width)), SetAttribute(Attribute::Reset))?;
+        }
+        queue!(self.out, MoveTo(0, footer + 2), SetAttribute(Attribute::Dim), Print(fit(&frame.hints, width)), SetAttribute(Attribute::Reset))?;
+
+        self.out.flush()
+    }
+
+    pub fn read_key(&mut self) -> io::Result<KeyEvent> {
+        loop {
+            if let Event::Key(key) = read()? {
+                if key.kind == KeyEventKind::Press {
+                    return Ok(key);
+                }
+            }
+        }
+    }
+
+}
+
+impl Drop for Terminal {
+
+    fn drop(&mut self) {
+        let _ = execute!(self.out, Show, LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+
+}
+
+pub struct Frame {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub selected: Option<usize>,
+    pub status: Option<String>,
+    pub hints: String
+}
+
+impl Frame {
+
+    pub fn new(title: &str, hints: &str) -> Frame {
+        Frame {
+            title: String::from(title),
+            lines: Vec::new(),
+            selected: None,
+            status: None,
+            hints: String::from(hints)
+        }
+    }
+
+}
+
+fn fit(line: &str, width: usize) -> String {
+    line.chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .take(width)
+        .collect()
+}
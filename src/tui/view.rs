@@ -0,0 +1,72 @@
+use rust_db_manager_core::domain::table::{
+    definition::table_definition::TableDefinition, group::table_data_group::TableDataGroup,
+};
+
+pub fn groups_as_lines(groups: &[TableDataGroup]) -> Vec<String> {
+    let mut sorted = groups.to_vec();
+    sorted.sort_by_key(|g| g.order());
+
+    let mut lines = Vec::new();
+    for group in sorted {
+        lines.push(format!("[{}]", group.name()));
+
+        let mut fields = group.fields();
+        fields.sort_by_key(|f| f.order());
+
+        let width = fields.iter().map(|f| f.name().chars().count()).max().unwrap_or(0);
+        for field in fields {
+            lines.push(format!("  {:width$}  {}", field.name(), field.value(), width = width));
+        }
+        lines.push(String::new());
+    }
+
+    lines
+}
+
+pub fn tables_as_lines(tables: &[TableDefinition]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for table in tables {
+        lines.push(format!("[{}]", table.title()));
+
+        let rows: Vec<Vec<String>> = table.rows().iter()
+            .map(|r| r.fields().iter().map(|f| f.data()).collect())
+            .collect();
+
+        let columns = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        let mut widths = vec![0; columns];
+        for row in &rows {
+            for (index, cell) in row.iter().enumerate() {
+                widths[index] = widths[index].max(cell.chars().count());
+            }
+        }
+
+        for (index, row) in rows.iter().enumerate() {
+            let cells: Vec<String> = row.iter().enumerate()
+                .map(|(i, cell)| format!("{:width$}", cell, width = widths[i]))
+                .collect();
+            lines.push(format!("  {}", cells.join(" │ ")));
+
+            let is_title = table.rows().get(index)
+                .is_some_and(|r| r.fields().iter().all(|f| f.is_title()));
+            if is_title {
+                let rule: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
+                lines.push(format!("  {}", rule.join("─┼─")));
+            }
+        }
+
+        if rows.is_empty() {
+            lines.push(String::from("  (no data)"));
+        }
+        lines.push(String::new());
+    }
+
+    lines
+}
+
+pub fn json_as_lines(json: &str) -> Vec<String> {
+    let pretty = serde_json::from_str::<serde_json::Value>(json)
+        .ok()
+        .and_then(|v| serde_json::to_string_pretty(&v).ok())
+        .unwrap_or_else(|| String::from(json));
+    pretty.lines().map(String::from).collect()
+}
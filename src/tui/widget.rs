@@ -0,0 +1,128 @@
+use std::io;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use super::terminal::{Frame, Terminal};
+
+pub enum Choice {
+    Select(usize),
+    Key(char, Option<usize>),
+    Back
+}
+
+pub fn list(terminal: &mut Terminal, title: &str, items: &[String], hints: &str, keys: &[char], status: &mut Option<String>, cursor: &mut usize) -> io::Result<Choice> {
+    loop {
+        let body = terminal.body_height();
+        if *cursor >= items.len() {
+            *cursor = items.len().saturating_sub(1);
+        }
+
+        let offset = (*cursor + 1).saturating_sub(body);
+
+        let mut frame = Frame::new(title, hints);
+        frame.status = status.clone();
+        if items.is_empty() {
+            frame.lines.push(String::from("(empty)"));
+        } else {
+            frame.lines = items.iter().skip(offset).take(body).cloned().collect();
+            frame.selected = Some(*cursor - offset);
+        }
+        terminal.render(&frame)?;
+
+        let key = terminal.read_key()?;
+        *status = None;
+
+        match key.code {
+            KeyCode::Up => *cursor = cursor.saturating_sub(1),
+            KeyCode::Down => *cursor = (*cursor + 1).min(items.len().saturating_sub(1)),
+            KeyCode::PageUp => *cursor = cursor.saturating_sub(body),
+            KeyCode::PageDown => *cursor = (*cursor + body).min(items.len().saturating_sub(1)),
+            KeyCode::Home => *cursor = 0,
+            KeyCode::End => *cursor = items.len().saturating_sub(1),
+            KeyCode::Enter if !items.is_empty() => return Ok(Choice::Select(*cursor)),
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => return Ok(Choice::Back),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(Choice::Back),
+            KeyCode::Char(c) if keys.contains(&c) => {
+                let selected = match items.is_empty() {
+                    true => None,
+                    false => Some(*cursor),
+                };
+                return Ok(Choice::Key(c, selected));
+            },
+            _ => {},
+        }
+    }
+}
+
+pub fn select(terminal: &mut Terminal, title: &str, items: &[String]) -> io::Result<Option<usize>> {
+    let mut cursor = 0;
+    let mut status = None;
+    match list(terminal, title, items, "↑/↓ move · Enter select · Esc cancel", &[], &mut status, &mut cursor)? {
+        Choice::Select(index) => Ok(Some(index)),
+        _ => Ok(None),
+    }
+}
+
+pub fn input(terminal: &mut Terminal, title: &str, label: &str, initial: &str, masked: bool) -> io::Result<Option<String>> {
+    let mut value = String::from(initial);
+    loop {
+        let mut frame = Frame::new(title, "Enter accept · Esc cancel");
+        frame.lines.push(String::from(label));
+        frame.lines.push(String::new());
+        let shown = match masked {
+            true => "*".repeat(value.chars().count()),
+            false => value.clone(),
+        };
+        frame.lines.push(format!("> {}_", shown));
+        terminal.render(&frame)?;
+
+        let key = terminal.read_key()?;
+        match key.code {
+            KeyCode::Enter => return Ok(Some(value)),
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(None),
+            KeyCode::Backspace => {
+                value.pop();
+            },
+            KeyCode::Char(c) => value.push(c),
+            _ => {},
+        }
+    }
+}
+
+pub fn confirm(terminal: &mut Terminal, title: &str, question: &str) -> io::Result<bool> {
+    let mut frame = Frame::new(title, "y confirm · any other key cancel");
+    frame.lines.push(String::from(question));
+    terminal.render(&frame)?;
+
+    let key = terminal.read_key()?;
+    Ok(matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')))
+}
+
+pub fn text(terminal: &mut Terminal, title: &str, lines: &[String]) -> io::Result<()> {
+    let mut offset = 0;
+    loop {
+        let body = terminal.body_height();
+        let max = lines.len().saturating_sub(body);
+        offset = offset.min(max);
+
+        let mut frame = Frame::new(title, "↑/↓ scroll · Esc back");
+        frame.lines = lines.iter().skip(offset).take(body).cloned().collect();
+        if lines.len() > body {
+            frame.status = Some(format!("Lines {}-{} of {}", offset + 1, (offset + body).min(lines.len()), lines.len()));
+        }
+        terminal.render(&frame)?;
+
+        let key = terminal.read_key()?;
+        match key.code {
+            KeyCode::Up => offset = offset.saturating_sub(1),
+            KeyCode::Down => offset = (offset + 1).min(max),
+            KeyCode::PageUp => offset = offset.saturating_sub(body),
+            KeyCode::PageDown => offset = (offset + body).min(max),
+            KeyCode::Home => offset = 0,
+            KeyCode::End => offset = max,
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Backspace | KeyCode::Left | KeyCode::Char('q') => return Ok(()),
+            _ => {},
+        }
+    }
+}